@@ -113,6 +113,7 @@ impl Args {
                 println!("{}", file.path().display());
                 return 0;
             }
+            linter.set_config(&config);
             linter.extend_ignore_re(&config.extend_ignore_re);
 
             let mut stderr = std::io::stderr().lock();
@@ -160,21 +160,23 @@ impl Config {
         walk
     }
 
+    /// Returns the config that applies to a file.
+    ///
+    /// The locale deduced from the path (e.g., `locales/de/common.json`) overrides the default one,
+    /// and the config of its file type is applied on top of it.
     pub fn config_from_path(&self, path: impl AsRef<Path>) -> Cow<'_, EngineConfig> {
         let path = path.as_ref();
-        let Some(extension) = path.extension() else {
-            return Cow::Borrowed(&self.default);
-        };
-        let Some(lang) = Language::from_filename(extension) else {
-            return Cow::Borrowed(&self.default);
-        };
-
-        let mut config = self.default.clone();
-        if let Some(type_config) = self.type_.patterns.get(lang.name()) {
-            config.update(type_config);
+        let mut config = Cow::Borrowed(&self.default);
+        if let Some(locale) = locale_from_path(path) {
+            config.to_mut().locale = Some(locale);
+        }
+        if let Some(type_config) =
+            Language::from_path(path).and_then(|lang| self.type_.patterns.get(lang.name()))
+        {
+            config.to_mut().update(type_config);
         }
 
-        Cow::Owned(config)
+        config
     }
 }
 
@@ -323,14 +325,74 @@ pub struct EngineConfig {
     /// Whether to check files
     pub check_file: Option<bool>,
 
+    /// Whether to skip translations marked as fuzzy or obsolete (e.g., in gettext catalogs)
+    pub skip_fuzzy: Option<bool>,
+
+    /// Syntax of the messages in translation files, to only check their literal text
+    pub message_format: Option<MessageFormat>,
+
     /// Additional list of regexes to prevent strings from being checked
     #[serde(with = "serde_regex")]
     pub extend_ignore_re: Vec<regex::Regex>,
+
+    /// Locale of the text (e.g., `en`, `es`, or `pt-BR`) to apply the rules of its language
+    pub locale: Option<String>,
+
+    /// Which quotation marks are expected when the locale is German
+    pub german_quotes: Option<GermanQuoteStyle>,
+
+    /// How em dashes should be written
+    pub em_dash: Option<EmDash>,
+
+    /// Additional list of words that must be preceded by `a` instead of `an` (e.g., `SQL` for `a SQL query`)
+    pub extend_a_words: Vec<String>,
+
+    /// Additional list of words that must be preceded by `an` instead of `a` (e.g., `herb` for `an herb`)
+    pub extend_an_words: Vec<String>,
+
+    /// Additional list of abbreviations that do not end a sentence (e.g., `Fig.`)
+    pub extend_abbreviations: Vec<String>,
+
+    /// Maximum number of consecutive exclamation or question marks (3 by default)
+    pub max_repeated_marks: Option<usize>,
+
+    /// Convention for the spacing between a number and its unit (deduced from the locale by default)
+    pub unit_convention: Option<UnitConvention>,
+
+    /// Kind of space between a number and its unit
+    pub unit_space: Option<UnitSpace>,
+
+    /// Expected case of the first letter of error messages
+    pub error_message_case: Option<ErrorMessageCase>,
+
+    /// Whether error messages must end with a period
+    pub error_message_period: Option<bool>,
+
+    /// Expected case of the headings in documents (e.g., in Markdown)
+    pub heading_case: Option<HeadingCase>,
+
+    /// Whether to enable pedantic rules (e.g., the multiplication sign in `1920×1080`)
+    pub pedantic: Option<bool>,
 }
 
 impl PartialEq for EngineConfig {
     fn eq(&self, other: &Self) -> bool {
         self.check_file == other.check_file
+            && self.skip_fuzzy == other.skip_fuzzy
+            && self.message_format == other.message_format
+            && self.locale == other.locale
+            && self.german_quotes == other.german_quotes
+            && self.em_dash == other.em_dash
+            && self.extend_a_words == other.extend_a_words
+            && self.extend_an_words == other.extend_an_words
+            && self.extend_abbreviations == other.extend_abbreviations
+            && self.max_repeated_marks == other.max_repeated_marks
+            && self.unit_convention == other.unit_convention
+            && self.unit_space == other.unit_space
+            && self.error_message_case == other.error_message_case
+            && self.error_message_period == other.error_message_period
+            && self.heading_case == other.heading_case
+            && self.pedantic == other.pedantic
             && self
                 .extend_ignore_re
                 .iter()
@@ -345,7 +407,22 @@ impl Default for EngineConfig {
     fn default() -> Self {
         Self {
             check_file: Some(true),
+            skip_fuzzy: None,
+            message_format: None,
             extend_ignore_re: Default::default(),
+            locale: None,
+            german_quotes: None,
+            em_dash: None,
+            extend_a_words: Default::default(),
+            extend_an_words: Default::default(),
+            extend_abbreviations: Default::default(),
+            max_repeated_marks: None,
+            unit_convention: None,
+            unit_space: None,
+            error_message_case: None,
+            error_message_period: None,
+            heading_case: None,
+            pedantic: None,
         }
     }
 }
@@ -356,14 +433,335 @@ impl EngineConfig {
         if let Some(source) = source.check_file {
             self.check_file = Some(source);
         }
+        if let Some(source) = source.skip_fuzzy {
+            self.skip_fuzzy = Some(source);
+        }
+        if let Some(source) = source.message_format {
+            self.message_format = Some(source);
+        }
         self.extend_ignore_re
             .extend_from_slice(&source.extend_ignore_re);
+        if let Some(source) = &source.locale {
+            self.locale = Some(source.clone());
+        }
+        if let Some(source) = source.german_quotes {
+            self.german_quotes = Some(source);
+        }
+        if let Some(source) = source.em_dash {
+            self.em_dash = Some(source);
+        }
+        self.extend_a_words
+            .extend(source.extend_a_words.iter().cloned());
+        self.extend_an_words
+            .extend(source.extend_an_words.iter().cloned());
+        self.extend_abbreviations
+            .extend(source.extend_abbreviations.iter().cloned());
+        if let Some(source) = source.max_repeated_marks {
+            self.max_repeated_marks = Some(source);
+        }
+        if let Some(source) = source.unit_convention {
+            self.unit_convention = Some(source);
+        }
+        if let Some(source) = source.unit_space {
+            self.unit_space = Some(source);
+        }
+        if let Some(source) = source.error_message_case {
+            self.error_message_case = Some(source);
+        }
+        if let Some(source) = source.error_message_period {
+            self.error_message_period = Some(source);
+        }
+        if let Some(source) = source.heading_case {
+            self.heading_case = Some(source);
+        }
+        if let Some(source) = source.pedantic {
+            self.pedantic = Some(source);
+        }
     }
 
     /// Whether to check this file type
     pub fn check_file(&self) -> bool {
         self.check_file.unwrap_or(true)
     }
+
+    /// Whether to skip translations marked as fuzzy or obsolete
+    pub fn skip_fuzzy(&self) -> bool {
+        self.skip_fuzzy.unwrap_or(false)
+    }
+
+    /// Whether pedantic rules are enabled
+    pub fn pedantic(&self) -> bool {
+        self.pedantic.unwrap_or(false)
+    }
+
+    /// Returns the language of the locale, if any (e.g., `pt` for `pt-BR`)
+    pub fn language(&self) -> Option<String> {
+        let locale = self.locale.as_deref()?;
+        let language = locale.split(['-', '_']).next()?;
+
+        Some(language.to_lowercase())
+    }
+}
+
+/// Defines how em dashes are expected to be written
+///
+/// # Example
+///
+/// ```toml
+/// [default]
+/// em-dash = "unspaced"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmDash {
+    /// An em dash surrounded by spaces (e.g., `this — and that`)
+    Spaced,
+
+    /// An em dash without spaces around it (e.g., `this—and that`)
+    Unspaced,
+
+    /// Only ASCII characters are used, so en and em dashes are written with a hyphen
+    Ascii,
+}
+
+/// Defines the syntax of the messages in translation files
+///
+/// # Example
+///
+/// ```toml
+/// [type.json]
+/// message-format = "icu"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MessageFormat {
+    /// ICU MessageFormat (e.g., `{count, plural, one {# file} other {# files}}`)
+    Icu,
+}
+
+/// Defines which quotation marks are expected in German
+///
+/// # Example
+///
+/// ```toml
+/// [default]
+/// locale = "de"
+/// german-quotes = "guillemets"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GermanQuoteStyle {
+    /// Low and high quotation marks (e.g., `„Hallo“`)
+    LowHigh,
+
+    /// Guillemets pointing inwards (e.g., `»Hallo«`)
+    Guillemets,
+}
+
+/// Defines how the first letter of error messages is expected to be written
+///
+/// # Example
+///
+/// ```toml
+/// [type.rust]
+/// error-message-case = "lowercase"
+/// error-message-period = false
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorMessageCase {
+    /// Error messages start with a lowercase letter (e.g., `failed to open the file`)
+    Lowercase,
+
+    /// Error messages start with an uppercase letter, like a sentence (e.g., `Failed to open the file.`)
+    Uppercase,
+}
+
+/// Defines how the words of headings are expected to be capitalized
+///
+/// # Example
+///
+/// ```toml
+/// [type.markdown]
+/// heading-case = "sentence"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeadingCase {
+    /// Only the first word and proper nouns are capitalized (e.g., `Supported languages`)
+    Sentence,
+
+    /// Every word is capitalized, except minor ones like articles and short prepositions (e.g., `Supported Languages`)
+    Title,
+}
+
+/// Defines where a space is expected between a number and its unit
+///
+/// # Example
+///
+/// ```toml
+/// [default]
+/// unit-convention = "si"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnitConvention {
+    /// A space before units, but not before a percent sign (e.g., `10 kg` and `5%`)
+    English,
+
+    /// A space before units, including a percent sign (e.g., `10 kg` and `5 %`)
+    Si,
+}
+
+/// Defines which space is expected between a number and its unit
+///
+/// # Example
+///
+/// ```toml
+/// [default]
+/// unit-space = "non-breaking"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnitSpace {
+    /// A regular space (U+0020)
+    Regular,
+
+    /// A non-breaking space (U+00A0) so the number and its unit stay on the same line
+    NonBreaking,
+}
+
+/// Languages whose locale can be deduced from the path of a file
+const PATH_LANGUAGES: &[&str] = &[
+    "ar", "bg", "ca", "cs", "da", "de", "el", "en", "es", "et", "fa", "fi", "fr", "he", "hi", "hr",
+    "hu", "it", "ja", "ko", "lt", "lv", "nb", "nl", "nn", "pl", "pt", "ro", "ru", "sk", "sl", "sr",
+    "sv", "th", "tr", "uk", "vi", "zh",
+];
+
+/// Names of the directories that usually contain translations
+const TRANSLATION_DIRS: &[&str] = &[
+    "i18n",
+    "l10n",
+    "lang",
+    "langs",
+    "languages",
+    "locale",
+    "locales",
+    "translations",
+];
+
+/// Extensions of the files that only contain translations, unlike generic data formats (e.g., `json` or `yml`)
+const TRANSLATION_EXTENSIONS: &[&str] =
+    &["arb", "ftl", "po", "properties", "strings", "xlf", "xliff"];
+
+/// Whether this is a locale of a known language (e.g., `de`, `de-AT`, `pt_BR`, or `zh-Hans`)
+fn is_locale(tag: &str) -> bool {
+    let mut subtags = tag.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+    let region = subtags.next();
+
+    PATH_LANGUAGES.contains(&language)
+        && subtags.next().is_none()
+        && region.is_none_or(|region| {
+            let is_country = region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase());
+            let is_script = region.len() == 4
+                && region.starts_with(|c: char| c.is_ascii_uppercase())
+                && region.chars().skip(1).all(|c| c.is_ascii_lowercase());
+            is_country || is_script
+        })
+}
+
+/// Returns the locale of the qualifiers of an Android resource directory
+/// (e.g., `fr` for `values-fr`, `pt-BR` for `values-pt-rBR`, or `sr-Latn` for `values-b+sr+Latn`)
+fn android_locale(qualifiers: &str) -> Option<String> {
+    let mut qualifiers = qualifiers.split('-');
+    let locale = qualifiers.find_map(|qualifier| {
+        if let Some(tag) = qualifier.strip_prefix("b+") {
+            return Some(tag.replace('+', "-"));
+        }
+
+        PATH_LANGUAGES
+            .contains(&qualifier)
+            .then(|| qualifier.to_owned())
+    })?;
+    let region = qualifiers
+        .next()
+        .and_then(|qualifier| qualifier.strip_prefix('r'))
+        .filter(|region| region.len() == 2);
+    let locale = match region {
+        Some(region) if !locale.contains('-') => format!("{locale}-{region}"),
+        _ => locale,
+    };
+
+    is_locale(&locale).then_some(locale)
+}
+
+/// Deduces the locale of a file from its path.
+///
+/// The locale can be the name of a file of translations (e.g., `fr.po` or `locales/messages.de-AT.yml`),
+/// which is only deduced for the formats of translations or inside a directory of translations,
+/// or the name of a directory inside a directory of translations (e.g., `locales/de/common.json`).
+/// For Java resource bundles, it is the suffix of the file name (e.g., `Messages_fr.properties`),
+/// and for Android resources, it is the qualifier of their directory (e.g., `res/values-fr/strings.xml`).
+/// For Apple strings files, it is the name of their bundle directory (e.g., `fr.lproj/Localizable.strings`).
+pub fn locale_from_path(path: &Path) -> Option<String> {
+    let file_stem = path.file_stem()?.to_str()?;
+    let file_locale = file_stem.rsplit('.').next().unwrap_or(file_stem);
+    let is_translation = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| TRANSLATION_EXTENSIONS.contains(&extension))
+        || path.parent().is_some_and(|parent| {
+            parent.components().any(|component| {
+                component
+                    .as_os_str()
+                    .to_str()
+                    .is_some_and(|dir| TRANSLATION_DIRS.contains(&dir))
+            })
+        });
+    if is_translation && is_locale(file_locale) {
+        return Some(file_locale.to_owned());
+    }
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "properties")
+    {
+        let bundle_locale = file_stem
+            .match_indices('_')
+            .filter_map(|(i, _)| file_stem.get(i + 1..))
+            .find(|suffix| is_locale(suffix));
+        if let Some(locale) = bundle_locale {
+            return Some(locale.to_owned());
+        }
+    }
+
+    let android_qualifiers = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|dir| dir.to_str()?.strip_prefix("values-"));
+    if let Some(locale) = android_qualifiers.and_then(android_locale) {
+        return Some(locale);
+    }
+
+    let apple_bundle = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|dir| dir.to_str()?.strip_suffix(".lproj"));
+    if let Some(locale) = apple_bundle.filter(|locale| is_locale(locale)) {
+        return Some(locale.to_owned());
+    }
+
+    let dirs = path
+        .parent()?
+        .components()
+        .filter_map(|component| component.as_os_str().to_str())
+        .collect::<Vec<_>>();
+    dirs.windows(2).rev().find_map(|window| match window {
+        [parent, dir] if TRANSLATION_DIRS.contains(parent) && is_locale(dir) => {
+            Some((*dir).to_owned())
+        }
+        _ => None,
+    })
 }
 
 fn find_project_files<'a>(
@@ -384,7 +782,7 @@ mod test {
 
     use tempfile::{NamedTempFile, tempdir};
 
-    use super::{Config, EngineConfig};
+    use super::{Config, EngineConfig, ErrorMessageCase, GermanQuoteStyle};
 
     #[test]
     fn from_file() {
@@ -413,6 +811,18 @@ check-file = false
         );
     }
 
+    #[test]
+    fn from_file_abbreviations() {
+        let config = r#"
+[default]
+extend-abbreviations = ["Fig."]
+        "#;
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), config).unwrap();
+        let config = Config::from_file(file.path()).unwrap().unwrap();
+        assert_eq!(config.default.extend_abbreviations, ["Fig."]);
+    }
+
     #[test]
     fn from_file_invalid() {
         let file = NamedTempFile::new().unwrap();
@@ -486,6 +896,129 @@ check-file = false
         );
     }
 
+    #[cfg(feature = "lang-rust")]
+    #[test]
+    fn config_from_path() {
+        let config = Config::from_toml(
+            r#"
+[type.rust]
+error-message-case = "lowercase"
+locale = "en"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.config_from_path("src/file.rs").error_message_case,
+            Some(ErrorMessageCase::Lowercase)
+        );
+        assert_eq!(config.config_from_path("file.md").error_message_case, None);
+        assert_eq!(config.config_from_path("README").error_message_case, None);
+        assert_eq!(
+            config.config_from_path("locales/de.rs").locale.as_deref(),
+            Some("en")
+        );
+        assert_eq!(
+            config.config_from_path("locales/de.md").locale.as_deref(),
+            Some("de")
+        );
+    }
+
+    #[test]
+    fn language() {
+        let config = |locale: Option<&str>| EngineConfig {
+            locale: locale.map(Into::into),
+            ..Default::default()
+        };
+        assert_eq!(config(None).language(), None);
+        assert_eq!(config(Some("es")).language().as_deref(), Some("es"));
+        assert_eq!(config(Some("pt_BR")).language().as_deref(), Some("pt"));
+        assert_eq!(config(Some("ES-mx")).language().as_deref(), Some("es"));
+    }
+
+    #[test]
+    fn locale_from_path() {
+        let locale = |path: &str| super::locale_from_path(Path::new(path));
+        assert_eq!(locale("locales/de.json").as_deref(), Some("de"));
+        assert_eq!(locale("i18n/messages.pt_BR.yml").as_deref(), Some("pt_BR"));
+        assert_eq!(
+            locale("locales/de-AT/common.json").as_deref(),
+            Some("de-AT")
+        );
+        assert_eq!(
+            locale("src/i18n/zh-Hans/app.json").as_deref(),
+            Some("zh-Hans")
+        );
+        assert_eq!(locale("src/lint/article.rs"), None);
+        assert_eq!(locale("src/de.rs"), None);
+        assert_eq!(locale("config/es.yml"), None);
+        assert_eq!(locale("hr.yaml"), None);
+        assert_eq!(locale("de.json"), None);
+        assert_eq!(locale("ftl/fr.ftl").as_deref(), Some("fr"));
+        assert_eq!(locale("docs/it.md"), None);
+        assert_eq!(locale("hi.c"), None);
+        assert_eq!(locale("po/fr.po").as_deref(), Some("fr"));
+        assert_eq!(locale("locales/it.md").as_deref(), Some("it"));
+        assert_eq!(locale("docs/de/README.md"), None);
+        assert_eq!(locale("locales/xx.json"), None);
+        assert_eq!(locale("locales/de-at.json"), None);
+        assert_eq!(
+            locale("src/main/resources/Messages_fr.properties").as_deref(),
+            Some("fr")
+        );
+        assert_eq!(
+            locale("app_messages_pt_BR.properties").as_deref(),
+            Some("pt_BR")
+        );
+        assert_eq!(locale("Messages.properties"), None);
+        assert_eq!(locale("settings_de.json"), None);
+        assert_eq!(
+            locale("app/src/main/res/values-fr/strings.xml").as_deref(),
+            Some("fr")
+        );
+        assert_eq!(
+            locale("res/values-pt-rBR-night/strings.xml").as_deref(),
+            Some("pt-BR")
+        );
+        assert_eq!(
+            locale("res/values-b+zh+Hans/strings.xml").as_deref(),
+            Some("zh-Hans")
+        );
+        assert_eq!(locale("res/values/strings.xml"), None);
+        assert_eq!(locale("res/values-night/strings.xml"), None);
+        assert_eq!(
+            locale("App/fr.lproj/Localizable.strings").as_deref(),
+            Some("fr")
+        );
+        assert_eq!(
+            locale("App/zh-Hans.lproj/InfoPlist.strings").as_deref(),
+            Some("zh-Hans")
+        );
+        assert_eq!(locale("App/Base.lproj/Localizable.strings"), None);
+
+        let config = Config::from_toml(
+            r#"
+[default]
+german-quotes = "guillemets"
+        "#,
+        )
+        .unwrap();
+        let config = config.config_from_path("locales/de/common.json");
+        assert_eq!(config.locale.as_deref(), Some("de"));
+        assert_eq!(config.german_quotes, Some(GermanQuoteStyle::Guillemets));
+
+        let config = Config::from_toml(
+            r#"
+[default]
+locale = "en"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.config_from_path("locales/de.json").locale.as_deref(),
+            Some("de")
+        );
+    }
+
     #[test]
     fn test_update_from_nothing() {
         let defaulted = Config::default();
@@ -1,39 +1,65 @@
 //! Parsers to find strings in various source code files
 use std::collections::HashSet;
 use std::ffi::OsStr;
+use std::path::Path;
 use std::sync::Arc;
 
 use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 
+use miette::SourceSpan;
+
 use tree_sitter::{Node, Parser, Query, QueryCursor, StreamingIterator, Tree};
 
 use crate::SharedSource;
 use crate::lock::LazyLock;
 use crate::tree::PreorderTraversal;
 
+#[cfg(feature = "lang-android")]
+mod android;
+#[cfg(feature = "lang-apple")]
+mod apple_strings;
+#[cfg(feature = "lang-asciidoc")]
+mod asciidoc;
 #[cfg(feature = "lang-c")]
 mod c;
 mod cargo_toml;
 #[cfg(feature = "lang-cpp")]
 mod cpp;
+#[cfg(feature = "lang-fluent")]
+mod fluent;
 #[cfg(feature = "lang-go")]
 mod go;
+#[cfg(feature = "lang-html")]
+mod html;
+#[cfg(feature = "lang-java")]
+mod java;
 #[cfg(feature = "lang-javascript")]
 mod javascript;
 #[cfg(feature = "lang-json")]
 mod json;
 #[cfg(feature = "lang-kotlin")]
 mod kotlin;
+#[cfg(feature = "lang-latex")]
+mod latex;
 #[cfg(feature = "lang-markdown")]
 mod markdown;
+mod message_format;
+#[cfg(feature = "lang-po")]
+mod po;
+#[cfg(feature = "lang-java")]
+mod properties;
 #[cfg(feature = "lang-python")]
 mod python;
+#[cfg(feature = "lang-rst")]
+mod rst;
 #[cfg(feature = "lang-rust")]
 mod rust;
 #[cfg(feature = "lang-toml")]
 mod toml;
 #[cfg(feature = "lang-typescript")]
 mod typescript;
+#[cfg(feature = "lang-apple")]
+mod xcstrings;
 #[cfg(feature = "lang-yaml")]
 mod yaml;
 
@@ -81,6 +107,17 @@ impl Mapping {
         lang!(javascript, "lang-javascript");
         lang!(typescript, "lang-typescript");
         lang!(markdown, "lang-markdown");
+        lang!(po, "lang-po");
+        lang!(html, "lang-html");
+        lang!(java, "lang-java");
+        lang!(properties, "lang-java");
+        lang!(android, "lang-android");
+        lang!(apple_strings, "lang-apple");
+        lang!(xcstrings, "lang-apple");
+        lang!(fluent, "lang-fluent");
+        lang!(rst, "lang-rst");
+        lang!(asciidoc, "lang-asciidoc");
+        lang!(latex, "lang-latex");
         // Takes precedence over the generic toml parser, so it needs to be last in the insertion order
         lang!(cargo_toml);
 
@@ -99,6 +136,16 @@ impl Mapping {
 
         self.glob_to_lang.get(*i).map(AsRef::as_ref)
     }
+
+    pub fn find_from_path(&self, path: &Path) -> Option<&Language> {
+        let mut matches = self.glob_set.matches(path);
+        if let Some(filename) = path.file_name() {
+            matches.extend(self.glob_set.matches(filename));
+        }
+        let i = matches.iter().max()?;
+
+        self.glob_to_lang.get(*i).map(AsRef::as_ref)
+    }
 }
 
 static MAPPING: LazyLock<Mapping> = LazyLock::new(Mapping::build);
@@ -146,6 +193,23 @@ impl Language {
         MAPPING.find_from_filename(filename)
     }
 
+    /// Finds the language to parse based on the path of a file
+    ///
+    /// Unlike [`Language::from_filename`], it also finds languages detected by their directories
+    /// (e.g., Android resources in `res/values/`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// #
+    /// # use typope::lang::Language;
+    /// assert!(Language::from_path(Path::new("src/main.rs")).is_some());
+    /// ```
+    pub fn from_path(path: &Path) -> Option<&Self> {
+        MAPPING.find_from_path(path)
+    }
+
     /// Returns an array of glob patterns of files supported by this language
     ///
     /// # Example
@@ -266,16 +330,30 @@ impl Parsed for ParsedQuery {
         });
         Box::new(nodes)
     }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        let comments = comments(&self.tree, source);
+
+        Box::new(texts(self.lintable_nodes(), source).chain(comments))
+    }
 }
 
 /// A string that can be checked with its offset within its source
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct LintableString {
     offset: usize,
     value: String,
 }
 
 impl LintableString {
+    /// Creates a string that can be checked, found at the given offset within its source
+    pub fn new(offset: usize, value: impl Into<String>) -> Self {
+        Self {
+            offset,
+            value: value.into(),
+        }
+    }
+
     /// Returns the string that can be checked for typos
     pub fn as_str(&self) -> &str {
         &self.value
@@ -302,6 +380,201 @@ impl From<&::toml::Spanned<String>> for LintableString {
     }
 }
 
+/// Kind of text in which a lintable string has been found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kind {
+    /// Text meant to be read by humans (e.g., a literal string or a paragraph in Markdown)
+    #[default]
+    Text,
+
+    /// A comment in source code
+    Comment,
+
+    /// A message given to a function or a macro that builds an error (e.g., `anyhow::bail!` or `errors.New`)
+    ErrorMessage,
+
+    /// A heading in a document (e.g., `# Title` in Markdown)
+    Heading,
+}
+
+/// Character that replaces a placeholder of a message (e.g., `{ $count }` in Fluent) in the text being checked,
+/// so the sentence around it keeps its structure
+const PLACEHOLDER: char = '\u{FFFC}';
+
+/// A part of a lintable string that has a different length in its source (e.g., `&nbsp;` decoded in HTML)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Replacement {
+    /// Index of the part within the lintable string
+    pub index: usize,
+
+    /// Length of the part within the lintable string
+    pub len: usize,
+
+    /// Length of the part within the source
+    pub source_len: usize,
+}
+
+/// Syntax in which a fix is written in a source that cannot contain some characters as is
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Encoding {
+    /// Characters outside of ASCII are written as `\uXXXX` escape sequences (e.g., Java properties in ISO-8859-1)
+    UnicodeEscapes,
+
+    /// Dashes, quotation marks, and non-breaking spaces are written as in TeX (e.g., `--` for an en dash)
+    Latex,
+}
+
+impl Encoding {
+    /// Returns the text as it must be written in the source
+    pub fn encode(self, text: &str) -> String {
+        let mut encoded = String::with_capacity(text.len());
+        for c in text.chars() {
+            match (self, c) {
+                (Self::UnicodeEscapes, c) if !c.is_ascii() => {
+                    let mut units = [0; 2];
+                    for unit in c.encode_utf16(&mut units) {
+                        encoded.push_str(&format!("\\u{unit:04x}"));
+                    }
+                }
+                (Self::Latex, '\u{A0}') => encoded.push('~'),
+                (Self::Latex, '–') => encoded.push_str("--"),
+                (Self::Latex, '—') => encoded.push_str("---"),
+                (Self::Latex, '“') => encoded.push_str("``"),
+                (Self::Latex, '”') => encoded.push_str("''"),
+                (_, c) => encoded.push(c),
+            }
+        }
+
+        encoded
+    }
+}
+
+/// A string that can be checked along with the kind of text it has been found in
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Lintable {
+    string: LintableString,
+    kind: Kind,
+    locale: Option<String>,
+    fuzzy: bool,
+    replacements: Vec<Replacement>,
+    encoding: Option<Encoding>,
+}
+
+impl Lintable {
+    pub fn new(string: LintableString, kind: Kind) -> Self {
+        Self {
+            string,
+            kind,
+            locale: None,
+            fuzzy: false,
+            replacements: Vec::new(),
+            encoding: None,
+        }
+    }
+
+    /// Sets the parts of the string that have a different length in the source, sorted by index
+    #[must_use]
+    pub fn with_replacements(mut self, replacements: Vec<Replacement>) -> Self {
+        self.replacements = replacements;
+        self
+    }
+
+    /// Sets how a fix is written in the source when it cannot contain some characters as is
+    /// (e.g., an escape sequence in a Java properties file encoded in ISO-8859-1)
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Sets the locale of the string when it is known from the source (e.g., the header of a translation file)
+    #[must_use]
+    pub fn with_locale(mut self, locale: Option<impl Into<String>>) -> Self {
+        self.locale = locale.map(Into::into);
+        self
+    }
+
+    /// Marks the string as a translation that may be outdated (e.g., a fuzzy or obsolete entry in gettext)
+    #[must_use]
+    pub fn fuzzy(mut self) -> Self {
+        self.fuzzy = true;
+        self
+    }
+
+    /// Returns the string that can be checked for typos
+    pub fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    /// Offset of the string within its source
+    pub fn offset(&self) -> usize {
+        self.string.offset()
+    }
+
+    /// Kind of text the string has been found in
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Locale of the string if it is known from the source, otherwise the configured one applies
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Whether the string is a translation that may be outdated
+    pub fn is_fuzzy(&self) -> bool {
+        self.fuzzy
+    }
+
+    /// Returns how a fix is written in the source, if it differs from the text of the fix
+    pub fn encoding(&self) -> Option<Encoding> {
+        self.encoding
+    }
+
+    /// Maps a span of the string to the span of the source it comes from
+    ///
+    /// A span that partially overlaps a replacement is extended to the whole replacement in the source.
+    pub fn source_span(&self, span: SourceSpan) -> SourceSpan {
+        let start = self.source_index(span.offset(), false);
+        let end = self.source_index(span.offset() + span.len(), true);
+
+        (start, end.saturating_sub(start)).into()
+    }
+
+    /// Maps an index of the string to an index of the source, moved to the start
+    /// or to the end of the replacement that contains it
+    fn source_index(&self, index: usize, to_end: bool) -> usize {
+        let mut source_len = 0;
+        let mut len = 0;
+        for replacement in &self.replacements {
+            // A span that starts right after a removed part of the string (e.g., a line continuation)
+            // starts after it in the source
+            let is_removed = replacement.len == 0 && !to_end;
+            if replacement.index > index || (replacement.index == index && !is_removed) {
+                break;
+            }
+            let end = replacement.index + replacement.len;
+            if end > index && !to_end {
+                return (self.offset() + replacement.index + source_len).saturating_sub(len);
+            }
+
+            source_len += replacement.source_len;
+            len += replacement.len;
+            if end > index {
+                return (self.offset() + end + source_len).saturating_sub(len);
+            }
+        }
+
+        (self.offset() + index + source_len).saturating_sub(len)
+    }
+}
+
+impl From<LintableString> for Lintable {
+    fn from(string: LintableString) -> Self {
+        Self::new(string, Kind::default())
+    }
+}
+
 /// Wrapper around a [`Node`] to make it easier to ignore ranges of bytes based on some children
 pub struct LintableNode<'t> {
     node: Node<'t>,
@@ -398,6 +671,95 @@ pub trait Parsed {
                 .flat_map(|node| node.lintable_strings(source).collect::<Vec<_>>()),
         )
     }
+
+    /// Returns an iterator over everything that can be checked in the source (e.g., strings and comments)
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.strings(source).map(Lintable::from))
+    }
+}
+
+/// Macros and functions that build an error from a message
+const ERROR_CONSTRUCTORS: &[&str] = &[
+    "Err",
+    "anyhow",
+    "anyhow::anyhow",
+    "anyhow::bail",
+    "bail",
+    "errors.New",
+    "fmt.Errorf",
+    "panic",
+];
+
+/// Macros, functions, and methods that build a string that can be given to an error constructor
+const STRING_BUILDERS: &[&str] = &[
+    "String::from",
+    "format",
+    "format_args",
+    "fmt.Sprintf",
+    "into",
+    "to_owned",
+    "to_string",
+];
+
+/// Returns the kind of text of a lintable node based on its ancestors
+fn text_kind(node: Node<'_>, source: &[u8]) -> Kind {
+    let mut ancestor = node.parent();
+    while let Some(node) = ancestor {
+        ancestor = node.parent();
+        let callee = match node.kind() {
+            "macro_invocation" => node.child_by_field_name("macro"),
+            "call_expression" | "call" => node.child_by_field_name("function"),
+            "new_expression" => node.child_by_field_name("constructor"),
+            "object_creation_expression" => node.child_by_field_name("type"),
+            _ => continue,
+        };
+        let Some(callee) = callee.and_then(|callee| callee.utf8_text(source).ok()) else {
+            break;
+        };
+
+        // The name of a method or of a type (e.g., `into` in `"message".into`)
+        let name = callee.rsplit(['.', ':']).next().unwrap_or(callee);
+        // Exceptions and error types (e.g., `raise ValueError(...)` or `throw new Error(...)`)
+        let is_error_type = name.starts_with(char::is_uppercase)
+            && (name.ends_with("Error") || name.ends_with("Exception"));
+        if ERROR_CONSTRUCTORS.contains(&callee) || is_error_type {
+            return Kind::ErrorMessage;
+        }
+        if !STRING_BUILDERS.contains(&callee) && !STRING_BUILDERS.contains(&name) {
+            break;
+        }
+    }
+
+    Kind::Text
+}
+
+/// Returns the strings of the lintable nodes along with the kind of text they have been found in
+fn texts<'t>(
+    nodes: impl Iterator<Item = LintableNode<'t>> + 't,
+    source: &'t [u8],
+) -> impl Iterator<Item = Lintable> + 't {
+    nodes.flat_map(move |node| {
+        let kind = text_kind(node.node, source);
+        node.lintable_strings(source)
+            .map(|string| Lintable::new(string, kind))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Node types of comments in the supported grammars
+const COMMENT_TYPES: &[&str] = &["comment", "line_comment", "block_comment", "html_comment"];
+
+/// Returns the comments found in a tree
+fn comments(tree: &Tree, source: &[u8]) -> Vec<Lintable> {
+    PreorderTraversal::from(tree)
+        .filter(|node| COMMENT_TYPES.contains(&node.kind()))
+        .flat_map(|node| {
+            LintableNode::from(node)
+                .lintable_strings(source)
+                .map(|string| Lintable::new(string, Kind::Comment))
+                .collect::<Vec<_>>()
+        })
+        .collect()
 }
 
 struct ParsedGeneric {
@@ -421,13 +783,19 @@ impl Parsed for ParsedGeneric {
             }),
         )
     }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        let comments = comments(&self.tree, source);
+
+        Box::new(texts(self.lintable_nodes(), source).chain(comments))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::ffi::OsStr;
 
-    use super::Language;
+    use super::{Encoding, Language};
 
     #[test]
     fn unknown_file_type() {
@@ -435,4 +803,16 @@ mod tests {
             Language::from_filename(OsStr::new("file.withextensionthatdoesnotexist")).is_none()
         );
     }
+
+    #[test]
+    fn encode() {
+        assert_eq!(
+            Encoding::UnicodeEscapes.encode("10–20 😀!"),
+            r"10\u201320 \ud83d\ude00!"
+        );
+        assert_eq!(
+            Encoding::Latex.encode("10–20 — “a”\u{A0}!"),
+            "10--20 --- ``a''~!"
+        );
+    }
 }
@@ -0,0 +1,381 @@
+use tree_sitter::{Node, Parser, Tree};
+
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement};
+
+/// Elements of inline markup whose content is not meant to be checked (e.g., a placeholder like `%1$s`)
+const IGNORED_ELEMENTS: &[&str] = &["xliff:g"];
+
+/// Decodes a predefined entity or a character reference without its `&` and `;` (e.g., `amp` or `#x2019`)
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix('x') {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+
+    match name {
+        "amp" => Some('&'),
+        "apos" => Some('\''),
+        "gt" => Some('>'),
+        "lt" => Some('<'),
+        "quot" => Some('"'),
+        _ => None,
+    }
+}
+
+/// Decodes an escape sequence of Android resources at the start of a string (e.g., `\'` or `\u2019`),
+/// along with its length
+fn decode_escape(escape: &str) -> Option<(char, usize)> {
+    let escaped = escape.chars().nth(1)?;
+    let decoded = match escaped {
+        'u' => {
+            let hex = escape.get(2..6)?;
+            let code = u32::from_str_radix(hex, 16).ok()?;
+            return Some((char::from_u32(code)?, 6));
+        }
+        'n' => '\n',
+        't' => '\t',
+        c => c,
+    };
+
+    Some((decoded, 1 + escaped.len_utf8()))
+}
+
+/// Builds a lintable string from the text of a range of the source, with its escape sequences decoded
+///
+/// The entities are only decoded outside of CDATA sections, and the double quotes that are not escaped
+/// are removed since they only preserve the whitespace of the string.
+fn decoded(source: &[u8], range: std::ops::Range<usize>, is_cdata: bool) -> Option<Lintable> {
+    let offset = range.start;
+    let text = std::str::from_utf8(source.get(range)?).ok()?;
+
+    let mut value = String::with_capacity(text.len());
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while let Some(c) = text.get(i..).and_then(|rest| rest.chars().next()) {
+        let rest = text.get(i..).unwrap_or_default();
+        let decoded = match c {
+            '\\' => decode_escape(rest),
+            '&' if !is_cdata => rest.get(1..).and_then(|reference| {
+                let (name, _) = reference.split_once(';')?;
+                Some((decode_entity(name)?, name.len() + 2))
+            }),
+            '"' => {
+                replacements.push(Replacement {
+                    index: value.len(),
+                    len: 0,
+                    source_len: 1,
+                });
+                i += 1;
+                continue;
+            }
+            _ => None,
+        };
+        let Some((decoded, source_len)) = decoded else {
+            value.push(c);
+            i += c.len_utf8();
+            continue;
+        };
+
+        replacements.push(Replacement {
+            index: value.len(),
+            len: decoded.len_utf8(),
+            source_len,
+        });
+        value.push(decoded);
+        i += source_len;
+    }
+
+    if value.trim().len() <= 3 {
+        return None;
+    }
+
+    Some(
+        Lintable::new(LintableString::new(offset, value), Kind::Text)
+            .with_replacements(replacements),
+    )
+}
+
+/// Returns the start tag of an element (e.g., `<string name="app_name">`)
+fn start_tag(element: Node<'_>) -> Option<Node<'_>> {
+    let mut cursor = element.walk();
+    element
+        .children(&mut cursor)
+        .find(|node| ["STag", "EmptyElemTag"].contains(&node.kind()))
+}
+
+/// Returns the name of an element (e.g., `string`)
+fn element_name<'s>(element: Node<'_>, source: &'s [u8]) -> Option<&'s str> {
+    let tag = start_tag(element)?;
+    let mut cursor = tag.walk();
+    let name = tag
+        .children(&mut cursor)
+        .find(|node| node.kind() == "Name")?;
+
+    name.utf8_text(source).ok()
+}
+
+/// Whether the strings of an element are meant to be translated (i.e., no `translatable="false"`)
+fn is_translatable(element: Node<'_>, source: &[u8]) -> bool {
+    let Some(tag) = start_tag(element) else {
+        return true;
+    };
+    let mut cursor = tag.walk();
+    let mut attributes = tag
+        .children(&mut cursor)
+        .filter(|node| node.kind() == "Attribute");
+
+    !attributes.any(|attribute| {
+        let mut cursor = attribute.walk();
+        let mut children = attribute.children(&mut cursor);
+        let name = children.next().and_then(|name| name.utf8_text(source).ok());
+        let value = children
+            .find(|node| node.kind() == "AttValue")
+            .and_then(|value| value.utf8_text(source).ok());
+
+        name == Some("translatable")
+            && value.map(|value| value.trim_matches(['"', '\''])) == Some("false")
+    })
+}
+
+/// Returns the child elements of an element
+fn child_elements(element: Node<'_>) -> Vec<Node<'_>> {
+    let mut cursor = element.walk();
+    let Some(content) = element
+        .children(&mut cursor)
+        .find(|node| node.kind() == "content")
+    else {
+        return Vec::new();
+    };
+
+    let mut cursor = content.walk();
+    content
+        .children(&mut cursor)
+        .filter(|node| node.kind() == "element")
+        .collect()
+}
+
+/// Parser for Android resources (e.g., `res/values/strings.xml`) that returns the strings meant to be translated
+struct ParsedAndroidResources {
+    tree: Tree,
+}
+
+impl ParsedAndroidResources {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter::Language::new(tree_sitter_xml::LANGUAGE_XML))?;
+        let Some(tree) = parser.parse(text.as_ref(), None) else {
+            anyhow::bail!("Invalid language");
+        };
+
+        Ok(Self { tree })
+    }
+
+    /// Finds the lintable strings in the text of an element, split around its inline markup (e.g., `<b>`)
+    fn text(element: Node<'_>, source: &[u8], lintables: &mut Vec<Lintable>) {
+        let mut cursor = element.walk();
+        let Some(content) = element
+            .children(&mut cursor)
+            .find(|node| node.kind() == "content")
+        else {
+            return;
+        };
+
+        // Consecutive character data and references form a single string
+        let mut text: Option<std::ops::Range<usize>> = None;
+        let mut cursor = content.walk();
+        for child in content.children(&mut cursor) {
+            if ["CharData", "EntityRef", "CharRef"].contains(&child.kind()) {
+                let start = text.map_or(child.start_byte(), |text| text.start);
+                text = Some(start..child.end_byte());
+                continue;
+            }
+            if let Some(text) = text.take() {
+                lintables.extend(decoded(source, text, false));
+            }
+
+            match child.kind() {
+                "CDSect" => {
+                    let mut cursor = child.walk();
+                    let cdata = child
+                        .children(&mut cursor)
+                        .find(|node| node.kind() == "CData");
+                    if let Some(cdata) = cdata {
+                        lintables.extend(decoded(source, cdata.byte_range(), true));
+                    }
+                }
+                "element" => {
+                    let name = element_name(child, source).unwrap_or_default();
+                    if !IGNORED_ELEMENTS.contains(&name) {
+                        Self::text(child, source, lintables);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(text) = text {
+            lintables.extend(decoded(source, text, false));
+        }
+    }
+}
+
+impl Parsed for ParsedAndroidResources {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables(source)
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        let mut lintables = Vec::new();
+        let Some(resources) = self.tree.root_node().child_by_field_name("root") else {
+            return Box::new(lintables.into_iter());
+        };
+
+        for element in child_elements(resources) {
+            if !is_translatable(element, source) {
+                continue;
+            }
+
+            match element_name(element, source) {
+                Some("string") => Self::text(element, source, &mut lintables),
+                Some("plurals" | "string-array") => {
+                    for item in child_elements(element) {
+                        if element_name(item, source) == Some("item") {
+                            Self::text(item, source, &mut lintables);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Box::new(lintables.into_iter())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for Android resources
+    pub fn android() -> Self {
+        Self {
+            name: "android",
+            detections: &["**/res/values*/*.xml"],
+            parser: Mode::Custom(Box::new(move |text| {
+                Ok(Box::new(ParsedAndroidResources::new(text)?))
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::SharedSource;
+    use crate::lang::LintableString;
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "android"));
+    }
+
+    #[test]
+    fn find_from_path() {
+        for path in [
+            "app/src/main/res/values/strings.xml",
+            "res/values-fr/arrays.xml",
+        ] {
+            assert_eq!(
+                "android",
+                Language::from_path(Path::new(path)).unwrap().name()
+            );
+        }
+        assert!(Language::from_path(Path::new("app/pom.xml")).is_none());
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="app_name" translatable="false">Not checked</string>
+    <!-- A comment -->
+    <string name="welcome">Welcome to <b>the app</b>, %1$s!</string>
+    <string name="greeting">Hello <xliff:g id="name">%s</xliff:g> and bye</string>
+    <string name="html"><![CDATA[Some <i>HTML</i> text]]></string>
+    <plurals name="files">
+        <item quantity="one">One file</item>
+        <item quantity="other">%d files</item>
+    </plurals>
+    <string-array name="planets">
+        <item>Mercury</item>
+        <item>Venus</item>
+    </string-array>
+    <color name="primary">#FF0000</color>
+</resources>
+"#;
+        let source = SharedSource::new("res/values/strings.xml", xml.as_bytes().to_vec());
+        let mut parsed = Language::android().parse(&source).unwrap();
+        let strings = parsed
+            .strings(source.as_ref())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            strings,
+            [
+                "Welcome to ",
+                "the app",
+                ", %1$s!",
+                "Hello ",
+                " and bye",
+                "Some <i>HTML</i> text",
+                "One file",
+                "%d files",
+                "Mercury",
+                "Venus",
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        let xml = r#"<resources>
+    <string name="quote">Don\'t say \"hello\" &amp; leave</string>
+    <string name="quoted">"It's fine"</string>
+</resources>
+"#;
+        let source = SharedSource::new("res/values/strings.xml", xml.as_bytes().to_vec());
+        let mut parsed = Language::android().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+
+        let quoted = lintables.pop().unwrap();
+        assert_eq!(quoted.as_str(), "It's fine");
+        assert_eq!(
+            quoted.source_span((0, 2).into()),
+            (xml.find("It's").unwrap(), 2).into()
+        );
+
+        let quote = lintables.pop().unwrap();
+        assert_eq!(
+            LintableString::new(quote.offset(), quote.as_str()),
+            LintableString::new(xml.find("Don").unwrap(), r#"Don't say "hello" & leave"#)
+        );
+        let apostrophe = "Don".len();
+        assert_eq!(
+            quote.source_span((apostrophe, 1).into()),
+            (xml.find("\\'").unwrap(), 2).into()
+        );
+        assert!(lintables.is_empty());
+    }
+}
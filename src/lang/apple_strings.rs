@@ -0,0 +1,262 @@
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement};
+
+/// Position within the text of a `.strings` file
+struct Scanner<'s> {
+    text: &'s str,
+    position: usize,
+}
+
+impl<'s> Scanner<'s> {
+    fn rest(&self) -> &'s str {
+        self.text.get(self.position..).unwrap_or_default()
+    }
+
+    /// Skips the whitespace and the comments (e.g., `/* comment */` or `// comment`)
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+
+            let end = if trimmed.starts_with("/*") {
+                trimmed.find("*/").map_or(trimmed.len(), |end| end + 2)
+            } else if trimmed.starts_with("//") {
+                trimmed.find('\n').unwrap_or(trimmed.len())
+            } else {
+                return;
+            };
+            self.position += end;
+        }
+    }
+
+    /// Consumes the given character if it is the next one
+    fn consume(&mut self, c: char) -> bool {
+        if self.rest().starts_with(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes a quoted string, and returns its content along with its offset
+    fn quoted(&mut self) -> Option<(usize, &'s str)> {
+        let rest = self.rest();
+        let content = rest.strip_prefix('"')?;
+        let mut chars = content.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => {
+                    let offset = self.position + 1;
+                    self.position = offset + i + 1;
+                    return Some((offset, content.get(..i)?));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Consumes an unquoted key (e.g., `NSCameraUsageDescription`)
+    fn bare(&mut self) -> bool {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !c.is_alphanumeric() && !"_.-$".contains(c))
+            .unwrap_or(rest.len());
+        self.position += end;
+
+        end > 0
+    }
+
+    /// Skips everything until the end of the current entry
+    fn recover(&mut self) {
+        let rest = self.rest();
+        self.position += rest.find(';').map_or(rest.len(), |end| end + 1);
+    }
+}
+
+/// Decodes the escape sequences of a quoted string (e.g., `\"` or `\U2019`)
+fn decoded(offset: usize, content: &str) -> Option<Lintable> {
+    let mut value = String::with_capacity(content.len());
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while let Some(c) = content.get(i..).and_then(|rest| rest.chars().next()) {
+        if c != '\\' {
+            value.push(c);
+            i += c.len_utf8();
+            continue;
+        }
+
+        let escape = content.get(i..).unwrap_or_default();
+        let Some(escaped) = escape.chars().nth(1) else {
+            break;
+        };
+        let (decoded, source_len) = match escaped {
+            'U' | 'u' => escape
+                .get(2..6)
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .and_then(char::from_u32)
+                .map_or((escaped, 2), |c| (c, 6)),
+            'n' => ('\n', 2),
+            't' => ('\t', 2),
+            'r' => ('\r', 2),
+            c => (c, 1 + c.len_utf8()),
+        };
+        replacements.push(Replacement {
+            index: value.len(),
+            len: decoded.len_utf8(),
+            source_len,
+        });
+        value.push(decoded);
+        i += source_len;
+    }
+
+    if value.trim().len() <= 3 {
+        return None;
+    }
+
+    Some(
+        Lintable::new(LintableString::new(offset, value), Kind::Text)
+            .with_replacements(replacements),
+    )
+}
+
+/// Parser for Apple strings files (e.g., `fr.lproj/Localizable.strings`) that returns the translated values
+///
+/// Only files encoded in UTF-8 are supported.
+struct ParsedAppleStrings {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedAppleStrings {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(text.as_ref())?;
+
+        let mut lintables = Vec::new();
+        let mut scanner = Scanner { text, position: 0 };
+        loop {
+            scanner.skip_trivia();
+            if scanner.rest().is_empty() {
+                break;
+            }
+
+            let has_key = scanner.quoted().is_some() || scanner.bare();
+            scanner.skip_trivia();
+            if !has_key || !scanner.consume('=') {
+                scanner.recover();
+                continue;
+            }
+            scanner.skip_trivia();
+            let Some((offset, content)) = scanner.quoted() else {
+                scanner.recover();
+                continue;
+            };
+            scanner.skip_trivia();
+            scanner.consume(';');
+
+            lintables.extend(decoded(offset, content));
+        }
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedAppleStrings {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for Apple strings files
+    pub fn apple_strings() -> Self {
+        Self {
+            name: "apple-strings",
+            detections: &["*.strings"],
+            parser: Mode::Custom(Box::new(move |text| {
+                Ok(Box::new(ParsedAppleStrings::new(text)?))
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::LintableString;
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "apple-strings"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "apple-strings",
+            Language::from_filename(OsStr::new("Localizable.strings"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let strings = r#"/* Title of the main screen */
+"welcome.title" = "Bienvenue dans l'application";
+// A comment on one line
+"Save the file" = "Enregistrer le fichier";
+NSCameraUsageDescription = "Pour scanner les documents";
+"quote" = "Il a dit \"bonjour\" \U2014 puis il est parti";
+"short" = "OK";
+"#;
+        let source = SharedSource::new("fr.lproj/Localizable.strings", strings.as_bytes().to_vec());
+        let mut parsed = Language::apple_strings().parse(&source).unwrap();
+        let lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+        let values = lintables
+            .iter()
+            .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str()))
+            .collect::<Vec<_>>();
+        let value =
+            |value: &str, start: &str| LintableString::new(strings.find(start).unwrap(), value);
+        assert_eq!(
+            values,
+            [
+                value("Bienvenue dans l'application", "Bienvenue"),
+                value("Enregistrer le fichier", "Enregistrer"),
+                value("Pour scanner les documents", "Pour"),
+                value("Il a dit \"bonjour\" — puis il est parti", "Il a dit"),
+            ]
+        );
+
+        let quote = lintables.last().unwrap();
+        let dash = "Il a dit \"bonjour\" ".len();
+        assert_eq!(
+            quote.source_span((dash, '—'.len_utf8()).into()),
+            (strings.find("\\U2014").unwrap(), 6).into()
+        );
+    }
+}
@@ -0,0 +1,506 @@
+use std::ops::Range;
+
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed};
+
+/// Labels of admonition paragraphs (e.g., `NOTE: Some text`)
+const ADMONITIONS: &[&str] = &["NOTE: ", "TIP: ", "IMPORTANT: ", "WARNING: ", "CAUTION: "];
+
+/// Styles of block attributes whose block is not meant to be checked (e.g., `[source,rust]`)
+const VERBATIM_STYLES: &[&str] = &[
+    "source",
+    "listing",
+    "literal",
+    "pass",
+    "stem",
+    "latexmath",
+    "asciimath",
+    "comment",
+];
+
+/// A line of the source without its line terminator
+#[derive(Clone, Copy)]
+struct Line<'s> {
+    offset: usize,
+    text: &'s str,
+}
+
+impl Line<'_> {
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    fn content(&self) -> &str {
+        self.text.trim_end()
+    }
+}
+
+/// Returns whether a line delimits a block whose content is not meant to be checked
+/// (e.g., `----` for a listing block), or `None` if the line is not a delimiter
+fn delimiter(content: &str) -> Option<bool> {
+    if content == "--" {
+        return Some(false);
+    }
+    if content.starts_with("```") {
+        return Some(true);
+    }
+    if ["|===", ",===", ":===", "!==="].contains(&content) {
+        return Some(true);
+    }
+
+    let mut chars = content.chars();
+    let first = chars.next()?;
+    if content.len() < 4 || !chars.all(|c| c == first) {
+        return None;
+    }
+    match first {
+        '-' | '.' | '+' | '/' => Some(true),
+        '=' | '*' | '_' => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the text of a section title without its markers (e.g., `Title` for `== Title`)
+fn section_title(content: &str) -> Option<&str> {
+    let marker = content.chars().next().filter(|c| ['=', '#'].contains(c))?;
+    let title = content.trim_start_matches(marker);
+    let level = content.len() - title.len();
+    if level > 6 || !title.starts_with(' ') {
+        return None;
+    }
+
+    // The markers can be repeated at the end of the title (e.g., `== Title ==`)
+    let title = title.trim();
+    Some(
+        title
+            .trim_end_matches(marker)
+            .strip_suffix(' ')
+            .unwrap_or(title),
+    )
+}
+
+/// Whether a line is an attribute entry (e.g., `:toc: left` or `:sectnums!:`)
+fn is_attribute_entry(content: &str) -> bool {
+    let Some(entry) = content.strip_prefix(':') else {
+        return false;
+    };
+    let Some(end) = entry.find(':') else {
+        return false;
+    };
+    let after = entry.get(end + 1..).unwrap_or_default();
+
+    end > 0 && !entry.starts_with(' ') && (after.is_empty() || after.starts_with(' '))
+}
+
+/// Whether a line is a block macro (e.g., `image::diagram.png[Diagram]` or `include::chapter.adoc[]`)
+fn is_block_macro(content: &str) -> bool {
+    let Some((name, target)) = content.split_once("::") else {
+        return false;
+    };
+
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && !target.starts_with(char::is_whitespace)
+        && target.ends_with(']')
+}
+
+/// Returns the length of the marker of a list item or of the label of an admonition at the start of
+/// a line (e.g., `* `, `. `, `1. `, `<1> `, or `NOTE: `), including the spaces that follow it
+fn marker_len(content: &str) -> usize {
+    if let Some(label) = ADMONITIONS.iter().find(|label| content.starts_with(*label)) {
+        return label.len();
+    }
+
+    let marker = match content.chars().next() {
+        Some(c @ ('*' | '.' | '-')) => content.len() - content.trim_start_matches(c).len(),
+        Some('<') => content.find('>').map_or(0, |end| end + 1),
+        Some(c) if c.is_ascii_alphanumeric() => {
+            let len = content
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(content.len())
+                .max(c.len_utf8());
+            if content.get(len..).is_some_and(|rest| rest.starts_with('.')) {
+                len + 1
+            } else {
+                0
+            }
+        }
+        _ => 0,
+    };
+
+    let rest = content.get(marker..).unwrap_or_default();
+    if marker == 0 || !rest.starts_with(' ') {
+        return 0;
+    }
+
+    content.len() - rest.trim_start().len()
+}
+
+/// Returns the end of the term of an item of a description list and the start of its definition
+/// (e.g., `CPU` and `The processor` in `CPU:: The processor`)
+fn description(content: &str) -> Option<(usize, usize)> {
+    let term_end = content.find("::").or_else(|| content.find(";;"))?;
+    let separator = content.get(term_end..)?;
+    let after = separator.trim_start_matches([':', ';']);
+    if term_end == 0 || separator.len() - after.len() > 4 {
+        return None;
+    }
+    if !(after.is_empty() || after.starts_with(char::is_whitespace)) {
+        return None;
+    }
+
+    Some((term_end, content.len() - after.trim_start().len()))
+}
+
+/// Whether a line starts a new block, which ends the current paragraph
+fn starts_block(content: &str) -> bool {
+    delimiter(content).is_some()
+        || content == "+"
+        || content.starts_with("//")
+        || (content.starts_with('[') && content.ends_with(']'))
+        || section_title(content).is_some()
+        || is_attribute_entry(content)
+        || marker_len(content) > 0
+}
+
+/// Returns the length of the inline markup at the start of a string whose text is not meant to be checked
+fn markup_len(text: &str, is_word_start: bool) -> Option<usize> {
+    // Monospace (e.g., `` `code` `` or ``` ``code`` ```)
+    if let Some(monospace) = text.strip_prefix("``") {
+        return Some(monospace.find("``")? + 4);
+    }
+    if let Some(monospace) = text.strip_prefix('`') {
+        return Some(monospace.find('`')? + 2);
+    }
+
+    // Passthroughs (e.g., `+text+`, `++text++`, `+++text+++`, or `pass:[text]`)
+    if let Some(passthrough) = text.strip_prefix("pass:[") {
+        return Some(passthrough.find(']')? + "pass:[".len() + 1);
+    }
+    if text.starts_with('+') {
+        let content = text.trim_start_matches('+');
+        let marks = text.len() - content.len();
+        if !is_word_start || marks > 3 || content.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let closing = "+".repeat(marks);
+        return Some(content.find(&closing)? + 2 * marks);
+    }
+
+    // Attribute reference (e.g., `{product-name}`)
+    let name = text.strip_prefix('{')?;
+    let end = name.find('}')?;
+    let is_name = end > 0
+        && name
+            .get(..end)?
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    is_name.then_some(end + 2)
+}
+
+/// Returns the ranges of a paragraph that can be checked, without its monospace text, passthroughs,
+/// and attribute references
+fn text_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    let mut previous: Option<char> = None;
+    while let Some(c) = text.get(i..).and_then(|rest| rest.chars().next()) {
+        let is_word_start = previous.is_none_or(|c| !c.is_alphanumeric());
+        let markup = "`+p{"
+            .contains(c)
+            .then(|| markup_len(text.get(i..)?, is_word_start))
+            .flatten();
+        if let Some(len) = markup {
+            ranges.push(start..i);
+            i += len;
+            start = i;
+            previous = text.get(..i).and_then(|text| text.chars().next_back());
+            continue;
+        }
+
+        previous = Some(c);
+        i += c.len_utf8();
+    }
+    ranges.push(start.min(text.len())..text.len());
+
+    ranges
+}
+
+/// Parser for AsciiDoc that returns the paragraphs, the titles, and the admonitions
+///
+/// Listing, literal, passthrough, and comment blocks are ignored, as well as attribute entries,
+/// tables, and inline monospace text.
+struct ParsedAsciiDoc {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedAsciiDoc {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(text.as_ref())?;
+        let lines = text
+            .split_inclusive('\n')
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line.len();
+                Some(Line {
+                    offset: start,
+                    text: line.trim_end_matches(['\n', '\r']),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut lintables = Vec::new();
+        let mut push = |offset: usize, text: &str, kind: Kind| {
+            for range in text_ranges(text) {
+                let Some(value) = text.get(range.clone()) else {
+                    continue;
+                };
+                if value.trim().len() <= 3 {
+                    continue;
+                }
+                let string = LintableString::new(offset + range.start, value);
+                lintables.push(Lintable::new(string, kind));
+            }
+        };
+        // Index of the first line that follows the paragraph starting at the given line
+        let paragraph_end = |start: usize| {
+            lines
+                .iter()
+                .skip(start + 1)
+                .position(|line| line.is_blank() || starts_block(line.content()))
+                .map_or(lines.len(), |len| start + 1 + len)
+        };
+
+        // Whether the next block is not meant to be checked, because of its attributes (e.g., `[source]`)
+        let mut is_verbatim = false;
+        let mut i = 0;
+        while let Some(line) = lines.get(i).copied() {
+            let content = line.content();
+            if line.is_blank() {
+                i += 1;
+                continue;
+            }
+
+            if let Some(is_delimited_verbatim) = delimiter(content) {
+                if is_delimited_verbatim || is_verbatim {
+                    // A fenced code block can be closed without its language (e.g., ```` ```rust ````)
+                    let closing = if content.starts_with("```") {
+                        "```"
+                    } else {
+                        content
+                    };
+                    let end = lines
+                        .iter()
+                        .skip(i + 1)
+                        .position(|line| line.content() == closing);
+                    i = end.map_or(lines.len(), |end| i + end + 2);
+                } else {
+                    i += 1;
+                }
+                is_verbatim = false;
+                continue;
+            }
+            if content.starts_with('[') && content.ends_with(']') {
+                let style = content
+                    .trim_start_matches('[')
+                    .split([',', '#', '.', '%', ']'])
+                    .next()
+                    .unwrap_or_default();
+                is_verbatim = VERBATIM_STYLES.contains(&style);
+                i += 1;
+                continue;
+            }
+            if content.starts_with("//")
+                || is_attribute_entry(content)
+                || is_block_macro(content)
+                || ["+", "'''", "<<<"].contains(&content)
+            {
+                i += 1;
+                continue;
+            }
+
+            if let Some(title) = section_title(content) {
+                let start = title.as_ptr() as usize - text.as_ptr() as usize;
+                push(start, title, Kind::Heading);
+                i += 1;
+                continue;
+            }
+            // Block title (e.g., `.An example`)
+            if let Some(title) = content
+                .strip_prefix('.')
+                .filter(|title| !title.starts_with(['.', ' ', '\t']))
+            {
+                push(line.offset + 1, title, Kind::Text);
+                i += 1;
+                continue;
+            }
+
+            let end = paragraph_end(i);
+            // Literal paragraphs are indented
+            if is_verbatim || line.text.starts_with(char::is_whitespace) {
+                is_verbatim = false;
+                i = end;
+                continue;
+            }
+
+            let mut start = line.offset + marker_len(content);
+            let last = lines.get(end - 1).copied().unwrap_or(line);
+            let paragraph_end = last.offset + last.content().len();
+            if let Some((term_end, definition_start)) = description(content) {
+                push(
+                    line.offset,
+                    content.get(..term_end).unwrap_or_default(),
+                    Kind::Text,
+                );
+                start = line.offset + definition_start;
+            }
+            push(
+                start,
+                text.get(start..paragraph_end).unwrap_or_default(),
+                Kind::Text,
+            );
+            i = end;
+        }
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedAsciiDoc {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for AsciiDoc
+    pub fn asciidoc() -> Self {
+        Self {
+            name: "asciidoc",
+            detections: &["*.adoc", "*.asciidoc"],
+            parser: Mode::Custom(Box::new(move |text| {
+                Ok(Box::new(ParsedAsciiDoc::new(text)?))
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::{Kind, LintableString};
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "asciidoc"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        for filename in ["guide.adoc", "guide.asciidoc"] {
+            assert_eq!(
+                "asciidoc",
+                Language::from_filename(OsStr::new(filename))
+                    .unwrap()
+                    .name()
+            );
+        }
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let adoc = r"= User Guide
+:toc: left
+:product-name: Example
+
+== Getting started
+
+This is a paragraph with `inline code` text
+and an attribute {product-name} on two lines.
+
+NOTE: Read this admonition carefully.
+
+[source,rust]
+----
+fn not_checked() {}
+----
+
+.An example block
+====
+Text inside an example block.
+====
+
+[source]
+Not checked either.
+
+....
+Literal block not checked.
+....
+
+++++
+<p>Passthrough not checked</p>
+++++
+
+* First item of the list
+* Second item with +passthrough text+ within it
+
+CPU:: The processor of the computer
+
+// A comment not checked
+image::diagram.png[Diagram]
+
+  Literal paragraph not checked.
+";
+        let source = SharedSource::new("guide.adoc", adoc.as_bytes().to_vec());
+        let mut parsed = Language::asciidoc().parse(&source).unwrap();
+        let strings = parsed.strings(source.as_ref()).collect::<Vec<_>>();
+        let string = |value: &str| LintableString::new(adoc.find(value).unwrap(), value);
+        assert_eq!(
+            strings,
+            [
+                string("User Guide"),
+                string("Getting started"),
+                string("This is a paragraph with "),
+                string(" text\nand an attribute "),
+                string(" on two lines."),
+                string("Read this admonition carefully."),
+                string("An example block"),
+                string("Text inside an example block."),
+                string("First item of the list"),
+                string("Second item with "),
+                string(" within it"),
+                string("The processor of the computer"),
+            ]
+        );
+
+        let mut parsed = Language::asciidoc().parse(&source).unwrap();
+        let headings = parsed
+            .lintables(source.as_ref())
+            .filter(|lintable| lintable.kind() == Kind::Heading)
+            .map(|lintable| lintable.as_str().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(headings, ["User Guide", "Getting started"]);
+    }
+}
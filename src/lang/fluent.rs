@@ -0,0 +1,334 @@
+use super::{
+    Kind, Language, Lintable, LintableNode, LintableString, Mode, PLACEHOLDER, Parsed, Replacement,
+};
+
+/// Returns the length of the identifier at the start of a string (e.g., `welcome-title`)
+fn identifier_len(text: &str) -> usize {
+    if !text.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return 0;
+    }
+
+    text.find(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '-')
+        .unwrap_or(text.len())
+}
+
+/// Returns the index of the pattern of a line that starts a message, a term, or an attribute
+/// (e.g., `Hello` in `welcome = Hello` or in `    .title = Hello`)
+fn pattern_start(line: &str) -> Option<usize> {
+    let content = line.trim_start_matches(' ');
+    let is_indented = content.len() < line.len();
+    let identifier = match (is_indented, content.strip_prefix(['-', '.'])) {
+        // Attributes are indented, while messages and terms are not
+        (true, Some(identifier)) if content.starts_with('.') => identifier,
+        (false, Some(identifier)) if content.starts_with('-') => identifier,
+        (false, None) => content,
+        _ => return None,
+    };
+    let len = identifier_len(identifier);
+    if len == 0 {
+        return None;
+    }
+
+    let separator = identifier.get(len..)?.trim_start_matches(' ');
+    let pattern = separator.strip_prefix('=')?;
+
+    Some(line.len() - pattern.len())
+}
+
+/// Whether the text following a line break continues the current pattern,
+/// i.e., its next line that is not blank is indented and does not start an attribute or a variant
+fn continues(rest: &str) -> bool {
+    for line in rest.split('\n') {
+        let content = line.trim_start_matches(' ');
+        if content.trim_end_matches('\r').is_empty() {
+            continue;
+        }
+
+        return content.len() < line.len() && !content.starts_with(['.', '[', '*', '}']);
+    }
+
+    false
+}
+
+/// Position within the text of a Fluent file along with the strings found so far
+struct Scanner<'s> {
+    text: &'s str,
+    position: usize,
+    lintables: Vec<Lintable>,
+}
+
+impl<'s> Scanner<'s> {
+    fn rest(&self) -> &'s str {
+        self.text.get(self.position..).unwrap_or_default()
+    }
+
+    /// Skips the blank characters (and the line breaks if `multiline` is set)
+    fn skip_blank(&mut self, multiline: bool) {
+        let rest = self.rest();
+        let blank = rest.trim_start_matches(|c| c == ' ' || (multiline && "\r\n".contains(c)));
+        self.position += rest.len() - blank.len();
+    }
+
+    /// Consumes a pattern, which can span multiple lines
+    ///
+    /// The placeables are replaced so that only the text is checked,
+    /// and the pattern of a variant stops at the end of its select expression.
+    fn pattern(&mut self, is_variant: bool) {
+        self.skip_blank(false);
+        let rest = self.rest();
+        if rest.starts_with(['\r', '\n']) {
+            let line_end = rest.find('\n').map_or(rest.len(), |end| end + 1);
+            if !continues(rest.get(line_end..).unwrap_or_default()) {
+                return;
+            }
+            self.skip_blank(true);
+        }
+
+        let offset = self.position;
+        let mut value = String::new();
+        let mut replacements = Vec::new();
+        while let Some(c) = self.rest().chars().next() {
+            match c {
+                '{' => {
+                    let start = self.position;
+                    self.placeable();
+                    replacements.push(Replacement {
+                        index: value.len(),
+                        len: PLACEHOLDER.len_utf8(),
+                        source_len: self.position - start,
+                    });
+                    value.push(PLACEHOLDER);
+                    continue;
+                }
+                '}' if is_variant => break,
+                '\n' if !continues(self.rest().get(1..).unwrap_or_default()) => break,
+                _ => {}
+            }
+
+            value.push(c);
+            self.position += c.len_utf8();
+        }
+
+        value.truncate(value.trim_end().len());
+        if value.trim().len() > 3 {
+            let string = LintableString::new(offset, value);
+            self.lintables
+                .push(Lintable::new(string, Kind::Text).with_replacements(replacements));
+        }
+    }
+
+    /// Consumes a placeable (e.g., `{ $count }` or `{ -brand-name }`), including the variants of
+    /// a select expression whose patterns are checked separately
+    fn placeable(&mut self) {
+        self.position += 1;
+        while let Some(c) = self.rest().chars().next() {
+            match c {
+                '"' => self.string_literal(),
+                '{' => self.placeable(),
+                '}' => {
+                    self.position += 1;
+                    return;
+                }
+                '-' if self.rest().starts_with("->") => {
+                    self.position += 2;
+                    self.variants();
+                }
+                c => self.position += c.len_utf8(),
+            }
+        }
+    }
+
+    /// Consumes a string literal of a placeable (e.g., `"{"`)
+    fn string_literal(&mut self) {
+        let rest = self.rest();
+        let mut chars = rest.char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' | '\n' => {
+                    self.position += i + 1;
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.position += rest.len();
+    }
+
+    /// Consumes the variants of a select expression (e.g., `[one] file *[other] files`)
+    /// until the end of its placeable
+    fn variants(&mut self) {
+        loop {
+            self.skip_blank(true);
+            let rest = self.rest();
+            let key = rest.strip_prefix('*').unwrap_or(rest);
+            if key.starts_with('[') {
+                let key_len = key.find(']').map_or(key.len(), |end| end + 1);
+                self.position += rest.len() - key.len() + key_len;
+                self.pattern(true);
+                continue;
+            }
+
+            match rest.chars().next() {
+                None | Some('}') => return,
+                Some(c) => self.position += c.len_utf8(),
+            }
+        }
+    }
+}
+
+/// Parser for Fluent files (e.g., `locales/fr/main.ftl`) that returns the values and the attributes
+/// of the messages and the terms
+struct ParsedFluent {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedFluent {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(text.as_ref())?;
+
+        let mut scanner = Scanner {
+            text,
+            position: 0,
+            lintables: Vec::new(),
+        };
+        while !scanner.rest().is_empty() {
+            let rest = scanner.rest();
+            let line = rest.split('\n').next().unwrap_or_default();
+            if let Some(start) = pattern_start(line) {
+                scanner.position += start;
+                scanner.pattern(false);
+            }
+
+            let rest = scanner.rest();
+            scanner.position += rest.find('\n').map_or(rest.len(), |end| end + 1);
+        }
+
+        let mut lintables = scanner.lintables;
+        lintables.sort_by_key(Lintable::offset);
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedFluent {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for Fluent files
+    pub fn fluent() -> Self {
+        Self {
+            name: "fluent",
+            detections: &["*.ftl"],
+            parser: Mode::Custom(Box::new(move |text| Ok(Box::new(ParsedFluent::new(text)?)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::LintableString;
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "fluent"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "fluent",
+            Language::from_filename(OsStr::new("main.ftl"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let ftl = r#"# A comment that is not checked
+-brand-name = Firefox Browser
+welcome = Welcome to { -brand-name }, { $user }!
+login-input = Predefined value
+    .placeholder = email@example.com
+    .aria-label = Login input value
+multiline =
+    This message spans
+    multiple lines.
+
+    With a blank line.
+emails = { $unread ->
+    [one] You have one unread email.
+   *[other] You have { $unread } unread emails.
+}
+short = OK
+"#;
+        let source = SharedSource::new("main.ftl", ftl.as_bytes().to_vec());
+        let mut parsed = Language::fluent().parse(&source).unwrap();
+        let strings = parsed.strings(source.as_ref()).collect::<Vec<_>>();
+        let string =
+            |value: &str, start: &str| LintableString::new(ftl.find(start).unwrap(), value);
+        assert_eq!(
+            strings,
+            [
+                string("Firefox Browser", "Firefox"),
+                string("Welcome to \u{FFFC}, \u{FFFC}!", "Welcome to"),
+                string("Predefined value", "Predefined"),
+                string("email@example.com", "email@"),
+                string("Login input value", "Login"),
+                string(
+                    "This message spans\n    multiple lines.\n\n    With a blank line.",
+                    "This message"
+                ),
+                string("You have one unread email.", "You have one"),
+                string("You have \u{FFFC} unread emails.", "You have {"),
+            ]
+        );
+    }
+
+    #[test]
+    fn source_spans() {
+        let ftl = "welcome = Hello { $user } !\n";
+        let source = SharedSource::new("main.ftl", ftl.as_bytes().to_vec());
+        let mut parsed = Language::fluent().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+        let lintable = lintables.pop().unwrap();
+        assert!(lintables.is_empty());
+
+        let placeable = "Hello ".len();
+        assert_eq!(
+            lintable.source_span((placeable, '\u{FFFC}'.len_utf8()).into()),
+            (ftl.find('{').unwrap(), "{ $user }".len()).into()
+        );
+        let space = "Hello \u{FFFC}".len();
+        assert_eq!(
+            lintable.source_span((space, 1).into()),
+            (ftl.find(" !").unwrap(), 1).into()
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use tree_sitter::{Node, Parser, Tree};
+
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement};
+
+/// Elements whose content is not meant to be checked
+const IGNORED_ELEMENTS: &[&str] = &["code", "pre"];
+
+/// Elements whose content is a heading
+const HEADING_ELEMENTS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// Attributes whose value is meant to be read by humans
+const LINTABLE_ATTRIBUTES: &[&str] = &["alt", "title", "placeholder", "aria-label"];
+
+/// Named character references that are decoded before being checked
+const ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("apos", '\''),
+    ("gt", '>'),
+    ("hellip", '…'),
+    ("laquo", '«'),
+    ("ldquo", '“'),
+    ("lsquo", '‘'),
+    ("lt", '<'),
+    ("mdash", '—'),
+    ("nbsp", '\u{A0}'),
+    ("ndash", '–'),
+    ("quot", '"'),
+    ("raquo", '»'),
+    ("rdquo", '”'),
+    ("rsquo", '’'),
+    ("shy", '\u{AD}'),
+    ("thinsp", '\u{2009}'),
+    ("times", '×'),
+];
+
+/// Decodes a character reference without its `&` and `;` (e.g., `nbsp` or `#x202F`)
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+
+    ENTITIES
+        .iter()
+        .find(|(entity, _)| *entity == name)
+        .map(|(_, c)| *c)
+}
+
+/// Builds a lintable string from the text of a range of the source, with its character references decoded
+fn decoded(source: &[u8], range: std::ops::Range<usize>, kind: Kind) -> Option<Lintable> {
+    let offset = range.start;
+    let text = std::str::from_utf8(source.get(range)?).ok()?;
+    if text.trim().len() <= 3 {
+        return None;
+    }
+
+    let mut value = String::with_capacity(text.len());
+    let mut replacements = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        let (before, reference) = rest.split_at(start);
+        value.push_str(before);
+        let decoded = reference
+            .get(1..)
+            .and_then(|reference| reference.split_once(';'))
+            .and_then(|(name, _)| Some((name, decode_entity(name)?)));
+        let Some((name, c)) = decoded else {
+            value.push('&');
+            rest = reference.get(1..).unwrap_or_default();
+            continue;
+        };
+
+        let source_len = name.len() + 2;
+        replacements.push(Replacement {
+            index: value.len(),
+            len: c.len_utf8(),
+            source_len,
+        });
+        value.push(c);
+        rest = reference.get(source_len..).unwrap_or_default();
+    }
+    value.push_str(rest);
+
+    Some(Lintable::new(LintableString::new(offset, value), kind).with_replacements(replacements))
+}
+
+/// Returns the name of a tag in lowercase (e.g., `h1` for `<H1 class="title">`)
+fn tag_name(tag: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut cursor = tag.walk();
+    let name = tag
+        .children(&mut cursor)
+        .find(|node| node.kind() == "tag_name")?;
+
+    Some(name.utf8_text(source).ok()?.to_lowercase())
+}
+
+/// Returns the values of the attributes of a tag that are meant to be read by humans
+fn attributes(tag: Node<'_>, source: &[u8]) -> Vec<Lintable> {
+    let mut cursor = tag.walk();
+    tag.children(&mut cursor)
+        .filter(|node| node.kind() == "attribute")
+        .filter_map(|attribute| {
+            let mut cursor = attribute.walk();
+            let mut children = attribute.children(&mut cursor);
+            let name = children.next()?.utf8_text(source).ok()?.to_lowercase();
+            if !LINTABLE_ATTRIBUTES.contains(&name.as_str()) {
+                return None;
+            }
+
+            let value = children.find(|node| {
+                ["attribute_value", "quoted_attribute_value"].contains(&node.kind())
+            })?;
+            let value = if value.kind() == "quoted_attribute_value" {
+                value.named_child(0)?
+            } else {
+                value
+            };
+
+            decoded(source, value.byte_range(), Kind::Text)
+        })
+        .collect()
+}
+
+/// Parser for HTML that returns the text content and the attributes meant to be read by humans
+struct ParsedHtml {
+    tree: Tree,
+}
+
+impl ParsedHtml {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter::Language::new(tree_sitter_html::LANGUAGE))?;
+        let Some(tree) = parser.parse(text.as_ref(), None) else {
+            anyhow::bail!("Invalid language");
+        };
+
+        Ok(Self { tree })
+    }
+
+    /// Finds the lintable strings among the children of an element
+    fn visit(node: Node<'_>, source: &[u8], kind: Kind, lintables: &mut Vec<Lintable>) {
+        // Consecutive text and character references form a single string
+        let mut text: Option<std::ops::Range<usize>> = None;
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if ["text", "entity"].contains(&child.kind()) {
+                let start = text.map_or(child.start_byte(), |text| text.start);
+                text = Some(start..child.end_byte());
+                continue;
+            }
+            if let Some(text) = text.take() {
+                lintables.extend(decoded(source, text, kind));
+            }
+
+            match child.kind() {
+                "start_tag" | "self_closing_tag" => lintables.extend(attributes(child, source)),
+                "element" => {
+                    let name = child
+                        .named_child(0)
+                        .and_then(|tag| tag_name(tag, source))
+                        .unwrap_or_default();
+                    if IGNORED_ELEMENTS.contains(&name.as_str()) {
+                        if let Some(tag) = child.named_child(0) {
+                            lintables.extend(attributes(tag, source));
+                        }
+                        continue;
+                    }
+
+                    let kind = if HEADING_ELEMENTS.contains(&name.as_str()) {
+                        Kind::Heading
+                    } else {
+                        kind
+                    };
+                    Self::visit(child, source, kind, lintables);
+                }
+                _ => {}
+            }
+        }
+        if let Some(text) = text {
+            lintables.extend(decoded(source, text, kind));
+        }
+    }
+}
+
+impl Parsed for ParsedHtml {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables(source)
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        let mut lintables = Vec::new();
+        Self::visit(self.tree.root_node(), source, Kind::Text, &mut lintables);
+
+        Box::new(
+            lintables
+                .into_iter()
+                .chain(super::comments(&self.tree, source)),
+        )
+    }
+}
+
+impl Language {
+    /// Creates a language parser for HTML
+    pub fn html() -> Self {
+        Self {
+            name: "html",
+            detections: &["*.html", "*.htm"],
+            parser: Mode::Custom(Box::new(move |text| Ok(Box::new(ParsedHtml::new(text)?)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::{Kind, LintableString};
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "html"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "html",
+            Language::from_filename(OsStr::new("index.html"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let html = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>My page</title>
+    <script>let text = "not checked";</script>
+    <style>p::before { content: "not checked"; }</style>
+  </head>
+  <body>
+    <h1>Welcome here</h1>
+    <p>Some text with <b>bold text</b> inside</p>
+    <pre>not checked</pre>
+    <p>Run <code>not checked</code> now</p>
+    <img src="image.png" alt="A nice picture">
+    <input placeholder='Your name' value="not checked">
+  </body>
+</html>
+"#;
+        let html = SharedSource::new("index.html", html.as_bytes().to_vec());
+        let mut parsed = Language::html().parse(&html).unwrap();
+        let strings = parsed
+            .strings(html.as_ref())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            strings,
+            [
+                "My page",
+                "Welcome here",
+                "Some text with",
+                "bold text",
+                "inside",
+                "A nice picture",
+                "Your name"
+            ]
+        );
+
+        let mut parsed = Language::html().parse(&html).unwrap();
+        let heading = parsed
+            .lintables(html.as_ref())
+            .find(|lintable| lintable.kind() == Kind::Heading)
+            .unwrap();
+        assert_eq!(heading.as_str(), "Welcome here");
+    }
+
+    #[test]
+    fn entities() {
+        let html = "<p>Bonjour&nbsp;! Tom &amp; Jerry&#8239;?</p>";
+        let source = SharedSource::new("index.html", html.as_bytes().to_vec());
+        let mut parsed = Language::html().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+        let lintable = lintables.pop().unwrap();
+        assert!(lintables.is_empty());
+        assert_eq!(
+            LintableString::new(lintable.offset(), lintable.as_str()),
+            LintableString::new(3, "Bonjour\u{A0}! Tom & Jerry\u{202F}?")
+        );
+
+        let nbsp = "Bonjour".len();
+        assert_eq!(
+            lintable.source_span((nbsp, 1).into()),
+            (3 + nbsp, "&nbsp;".len()).into()
+        );
+        assert_eq!(
+            lintable.source_span((nbsp + 2, 1).into()),
+            (3 + "Bonjour&nbsp;".len(), 1).into()
+        );
+        assert_eq!(lintable.source_span((0, 1).into()), (3, 1).into());
+    }
+}
@@ -0,0 +1,286 @@
+use tree_sitter::{Node, Parser, Tree};
+
+use crate::tree::PreorderTraversal;
+
+use super::{Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement};
+
+/// Delimiter of a text block
+const TEXT_BLOCK: &str = "\"\"\"";
+
+/// Decodes an escape sequence at the start of a string (e.g., `\"` or `\s`), along with its length
+fn decode_escape(escape: &str) -> Option<(char, usize)> {
+    let escaped = escape.chars().nth(1)?;
+    let decoded = match escaped {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        's' => ' ',
+        'b' => '\u{8}',
+        'f' => '\u{C}',
+        c => c,
+    };
+
+    Some((decoded, 1 + escaped.len_utf8()))
+}
+
+/// Builds a lintable string from a text block, as it is rendered: the indentation common to its lines
+/// and the trailing whitespace of each line are stripped, and the escape sequences are decoded
+fn text_block(node: Node<'_>, source: &[u8]) -> Option<Lintable> {
+    let text = node.utf8_text(source).ok()?;
+    let content = text.strip_prefix(TEXT_BLOCK)?.strip_suffix(TEXT_BLOCK)?;
+    // The content starts on the line after the opening delimiter
+    let first_line = content.find('\n')? + 1;
+    let content_offset = node.start_byte() + TEXT_BLOCK.len() + first_line;
+    let content = content.get(first_line..)?;
+
+    let mut lines = content
+        .split_inclusive('\n')
+        .scan(content_offset, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line.trim_end_matches(['\n', '\r'])))
+        })
+        .collect::<Vec<_>>();
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    // The line of the closing delimiter counts for the indentation, even if it is blank
+    let closing_line = lines
+        .last()
+        .filter(|(_, line)| line.trim().is_empty())
+        .map(|(_, line)| indentation(line));
+    if closing_line.is_some() {
+        lines.pop();
+    }
+    let indent = lines
+        .iter()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(_, line)| indentation(line))
+        .chain(closing_line)
+        .min()?;
+
+    let mut value = String::new();
+    let mut replacements = Vec::new();
+    let mut offset = None;
+    // End of the text of the previous line within the source, and whether it ends with a line continuation
+    let mut previous: Option<(usize, bool)> = None;
+    for (start, line) in lines {
+        let body = line.get(indent.min(line.len())..)?;
+        let body = body.trim_end_matches([' ', '\t']);
+        let body_start = start + indent.min(line.len());
+        match previous {
+            None => offset = Some(body_start),
+            Some((end, continuation)) => {
+                // The line terminator along with the whitespace that is stripped around it
+                let (decoded, len) = if continuation { ("", 0) } else { ("\n", 1) };
+                replacements.push(Replacement {
+                    index: value.len(),
+                    len,
+                    source_len: body_start - end,
+                });
+                value.push_str(decoded);
+            }
+        }
+
+        let mut continuation = false;
+        let mut i = 0;
+        while let Some(c) = body.get(i..).and_then(|rest| rest.chars().next()) {
+            if c != '\\' {
+                value.push(c);
+                i += c.len_utf8();
+                continue;
+            }
+
+            let Some((decoded, source_len)) = decode_escape(body.get(i..).unwrap_or_default())
+            else {
+                // A `\` at the end of a line joins it with the next one
+                continuation = true;
+                break;
+            };
+            replacements.push(Replacement {
+                index: value.len(),
+                len: decoded.len_utf8(),
+                source_len,
+            });
+            value.push(decoded);
+            i += source_len;
+        }
+        previous = Some((body_start + i, continuation));
+    }
+
+    if value.trim().is_empty() {
+        return None;
+    }
+
+    let string = LintableString::new(offset?, value);
+    Some(Lintable::new(string, super::text_kind(node, source)).with_replacements(replacements))
+}
+
+/// Parser for Java that returns its strings, with the text blocks as they are rendered, and its comments
+struct ParsedJava {
+    tree: Tree,
+}
+
+impl ParsedJava {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter::Language::new(tree_sitter_java::LANGUAGE))?;
+        let Some(tree) = parser.parse(text.as_ref(), None) else {
+            anyhow::bail!("Invalid language");
+        };
+
+        Ok(Self { tree })
+    }
+
+    /// Returns the strings and the text blocks of the source
+    fn texts(&self, source: &[u8]) -> Vec<Lintable> {
+        PreorderTraversal::from(&self.tree)
+            .flat_map(|node| match node.kind() {
+                "string_fragment" if node.byte_range().len() > 3 => LintableNode::from(node)
+                    .lintable_strings(source)
+                    .map(|string| Lintable::new(string, super::text_kind(node, source)))
+                    .collect(),
+                "string_literal" => {
+                    let is_text_block = source
+                        .get(node.byte_range())
+                        .is_some_and(|text| text.starts_with(TEXT_BLOCK.as_bytes()));
+                    if is_text_block {
+                        text_block(node, source).into_iter().collect()
+                    } else {
+                        Vec::new()
+                    }
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+}
+
+impl Parsed for ParsedJava {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.texts(source)
+                .into_iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(
+            self.texts(source)
+                .into_iter()
+                .chain(super::comments(&self.tree, source)),
+        )
+    }
+}
+
+impl Language {
+    /// Creates a language parser for Java
+    pub fn java() -> Self {
+        Self {
+            name: "java",
+            detections: &["*.java"],
+            parser: Mode::Custom(Box::new(move |text| Ok(Box::new(ParsedJava::new(text)?)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::LintableString;
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "java"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "java",
+            Language::from_filename(OsStr::new("File.java"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let java = r#"
+class Main {
+    public static void main(String[] args) {
+        String s = "abcdef";
+        System.out.println("Hello, World!\n(" + s + ") ghijkl");
+        String block = """
+            A text block""";
+    }
+}
+"#;
+        let java = SharedSource::new("Main.java", java.as_bytes().to_vec());
+        let mut parsed = Language::java().parse(&java).unwrap();
+        let strings = parsed.strings(java.as_ref()).collect::<Vec<_>>();
+        assert_eq!(
+            strings,
+            [
+                LintableString {
+                    offset: 79,
+                    value: "abcdef".into()
+                },
+                LintableString {
+                    offset: 116,
+                    value: "Hello, World!".into()
+                },
+                LintableString {
+                    offset: 141,
+                    value: ") ghijkl".into()
+                },
+                LintableString {
+                    offset: 192,
+                    value: "A text block".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn text_block() {
+        let java = "class Main {\n    String block = \"\"\"\n        Hello \\\"world\\\" !  \n          \
+                    Indented line\n        Joined \\\n        line\n        \"\"\";\n}\n";
+        let source = SharedSource::new("Main.java", java.as_bytes().to_vec());
+        let mut parsed = Language::java().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+        let block = lintables.pop().unwrap();
+        assert!(lintables.is_empty());
+        assert_eq!(
+            block.as_str(),
+            "Hello \"world\" !\n  Indented line\nJoined line"
+        );
+        assert_eq!(block.offset(), java.find("Hello").unwrap());
+
+        let space = "Hello \"world\"".len();
+        assert_eq!(
+            block.source_span((space, 1).into()),
+            (java.find(" !").unwrap(), 1).into()
+        );
+        let indented = block.as_str().find("Indented").unwrap();
+        assert_eq!(
+            block.source_span((indented, 8).into()),
+            (java.find("Indented").unwrap(), 8).into()
+        );
+        let line = block.as_str().rfind("line").unwrap();
+        assert_eq!(
+            block.source_span((line, 4).into()),
+            (java.rfind("line").unwrap(), 4).into()
+        );
+    }
+}
@@ -0,0 +1,524 @@
+use tree_sitter::{Node, Parser};
+
+use crate::tree::PreorderTraversal;
+
+use super::{
+    Encoding, Kind, Language, Lintable, LintableNode, LintableString, Mode, PLACEHOLDER, Parsed,
+    Replacement,
+};
+
+const NO_BREAK_SPACE: char = '\u{A0}';
+const EN_DASH: char = '–';
+const EM_DASH: char = '—';
+const LEFT_QUOTATION_MARK: char = '“';
+const RIGHT_QUOTATION_MARK: char = '”';
+
+/// Types of nodes that end a paragraph and whose content is not meant to be checked
+const BLOCK_TYPES: &[&str] = &[
+    "begin",
+    "end",
+    "block_comment",
+    "comment_environment",
+    "displayed_equation",
+    "math_environment",
+    "verbatim_environment",
+    "listing_environment",
+    "minted_environment",
+    "pycode_environment",
+    "luacode_environment",
+    "sageblock_environment",
+    "sagesilent_environment",
+    "asy_environment",
+    "asydef_environment",
+];
+
+/// Types of nodes that are removed from the text without breaking the sentence around them
+const REMOVED_TYPES: &[&str] = &["line_comment", "label_definition", "{", "}"];
+
+/// Types of nodes whose children are part of the text
+const CONTAINER_TYPES: &[&str] = &[
+    "source_file",
+    "generic_environment",
+    "text",
+    "curly_group",
+    "operator",
+];
+
+/// Types of nodes of sectioning commands (e.g., `\section{Title}`)
+const SECTION_TYPES: &[&str] = &[
+    "part",
+    "chapter",
+    "section",
+    "subsection",
+    "subsubsection",
+    "paragraph",
+    "subparagraph",
+];
+
+/// Commands whose arguments are part of the text (e.g., `\emph{text}`)
+const TEXT_COMMANDS: &[&str] = &[
+    "emph",
+    "enquote",
+    "mbox",
+    "textbf",
+    "textit",
+    "textmd",
+    "textrm",
+    "textsc",
+    "textsf",
+    "textsl",
+    "textup",
+    "underline",
+];
+
+/// Commands whose arguments are checked apart from the text around them (e.g., `\footnote{text}`)
+const NOTE_COMMANDS: &[&str] = &["footnote", "footnotetext", "marginpar", "thanks"];
+
+/// Text of a paragraph built from the nodes of the document
+///
+/// Commands and formulas are replaced by a placeholder, `~` by a non-breaking space,
+/// `--` or `---` by an en dash or an em dash, and ``` `` ``` or `''` by a quotation mark.
+/// The fixes are written back with the same syntax.
+struct Builder<'s> {
+    source: &'s str,
+    position: usize,
+    start: Option<usize>,
+    value: String,
+    replacements: Vec<Replacement>,
+    lintables: Vec<Lintable>,
+}
+
+impl<'s> Builder<'s> {
+    fn new(source: &'s str) -> Self {
+        Self {
+            source,
+            position: 0,
+            start: None,
+            value: String::new(),
+            replacements: Vec::new(),
+            lintables: Vec::new(),
+        }
+    }
+
+    /// Ends the current paragraph
+    fn flush(&mut self, kind: Kind) {
+        let value = std::mem::take(&mut self.value);
+        let replacements = std::mem::take(&mut self.replacements);
+        let Some(start) = self.start.take() else {
+            return;
+        };
+        let value = value.trim_end();
+        if value.trim().len() <= 3 {
+            return;
+        }
+
+        let string = LintableString::new(start, value);
+        self.lintables.push(
+            Lintable::new(string, kind)
+                .with_replacements(replacements)
+                .with_encoding(Encoding::Latex),
+        );
+    }
+
+    /// Whether the last character of the text has been copied as is from the source
+    fn ends_with_source(&self, c: char) -> bool {
+        self.value.ends_with(c)
+            && self
+                .replacements
+                .last()
+                .is_none_or(|replacement| replacement.index + replacement.len < self.value.len())
+    }
+
+    fn replace(&mut self, c: char, source_len: usize) {
+        self.replacements.push(Replacement {
+            index: self.value.len(),
+            len: c.len_utf8(),
+            source_len,
+        });
+        self.value.push(c);
+    }
+
+    /// Appends the source up to the given index, unless a blank line ends the paragraph
+    fn push_until(&mut self, end: usize) {
+        let text = self.source.get(self.position..end).unwrap_or_default();
+        self.position = end.max(self.position);
+        if self.start.is_none() {
+            return;
+        }
+        if text.matches('\n').count() > 1 {
+            self.flush(Kind::Text);
+            return;
+        }
+
+        for c in text.chars() {
+            match c {
+                '~' => self.replace(NO_BREAK_SPACE, 1),
+                '-' if self.ends_with_source('-') => {
+                    self.value.pop();
+                    self.replace(EN_DASH, 2);
+                }
+                '`' if self.ends_with_source('`') => {
+                    self.value.pop();
+                    self.replace(LEFT_QUOTATION_MARK, 2);
+                }
+                '\'' if self.ends_with_source('\'') => {
+                    self.value.pop();
+                    self.replace(RIGHT_QUOTATION_MARK, 2);
+                }
+                '-' if self.value.ends_with(EN_DASH)
+                    && self.replacements.last().is_some_and(|replacement| {
+                        replacement.source_len == 2
+                            && replacement.index + replacement.len == self.value.len()
+                    }) =>
+                {
+                    self.replacements.pop();
+                    self.value.pop();
+                    self.replace(EM_DASH, 3);
+                }
+                c => self.value.push(c),
+            }
+        }
+    }
+
+    fn push(&mut self, node: Node<'_>) {
+        self.push_until(node.start_byte());
+        self.start.get_or_insert(node.start_byte());
+        self.push_until(node.end_byte());
+    }
+
+    fn remove(&mut self, node: Node<'_>) {
+        self.push_until(node.start_byte());
+        let mut end = node.end_byte();
+        // A comment also removes the end of its line
+        if node.kind() == "line_comment"
+            && self
+                .source
+                .get(end..)
+                .is_some_and(|rest| rest.starts_with('\n'))
+        {
+            end += 1;
+        }
+        if self.start.is_some() {
+            self.replacements.push(Replacement {
+                index: self.value.len(),
+                len: 0,
+                source_len: end - node.start_byte(),
+            });
+        }
+        self.position = end;
+    }
+
+    /// Replaces the source from the start of the node to the given index by a placeholder
+    fn mask(&mut self, node: Node<'_>, end: usize) {
+        self.push_until(node.start_byte());
+        self.start.get_or_insert(node.start_byte());
+        self.replace(PLACEHOLDER, end.saturating_sub(node.start_byte()));
+        self.position = end;
+    }
+
+    fn visit(&mut self, node: Node<'_>) {
+        if node.start_byte() < self.position {
+            return;
+        }
+
+        let kind = node.kind();
+        if BLOCK_TYPES.contains(&kind) {
+            self.flush(Kind::Text);
+            self.position = node.end_byte();
+        } else if REMOVED_TYPES.contains(&kind) {
+            self.remove(node);
+        } else if SECTION_TYPES.contains(&kind) {
+            self.section(node);
+        } else if kind == "enum_item" {
+            self.flush(Kind::Text);
+            self.command(node);
+        } else if kind == "caption" {
+            self.flush(Kind::Text);
+            self.command(node);
+            self.flush(Kind::Text);
+        } else if kind == "generic_command" {
+            self.generic_command(node);
+        } else if CONTAINER_TYPES.contains(&kind) {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                self.visit(child);
+            }
+        } else if node.child_count() == 0 {
+            self.push(node);
+        } else {
+            self.mask(node, node.end_byte());
+        }
+    }
+
+    /// Visits a sectioning command, whose title is checked on its own as a heading
+    ///
+    /// The titles of paragraphs are run-in headings that often end with a period, so they are checked as text.
+    fn section(&mut self, node: Node<'_>) {
+        self.flush(Kind::Text);
+        if let Some(title) = node.child_by_field_name("text") {
+            self.position = title.start_byte();
+            self.visit(title);
+            if node.kind().ends_with("paragraph") {
+                self.flush(Kind::Text);
+            } else {
+                self.flush(Kind::Heading);
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child);
+        }
+    }
+
+    /// Visits a command whose arguments in braces are part of the text, or that is followed by text
+    /// (e.g., `\item`), while its other arguments (e.g., `[label]`) are replaced by a placeholder
+    fn command(&mut self, node: Node<'_>) {
+        let command = node.child_by_field_name("command");
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if command.is_some_and(|command| command.id() == child.id()) {
+                self.remove(child);
+            } else if child.kind() == "curly_group" || !child.kind().contains("group") {
+                self.visit(child);
+            } else if child.start_byte() >= self.position {
+                self.mask(child, child.end_byte());
+            }
+        }
+    }
+
+    /// Visits a note (e.g., `\footnote{text}`), whose text is checked on its own
+    /// and replaced by a placeholder in the text around it
+    fn note(&mut self, node: Node<'_>) {
+        let mut note = Builder::new(self.source);
+        note.position = node.start_byte();
+        note.command(node);
+        note.flush(Kind::Text);
+        self.lintables.append(&mut note.lintables);
+        self.mask(node, node.end_byte());
+    }
+
+    fn generic_command(&mut self, node: Node<'_>) {
+        let name = node
+            .child_by_field_name("command")
+            .and_then(|command| command.utf8_text(self.source.as_bytes()).ok())
+            .unwrap_or_default();
+        let name = name.strip_prefix('\\').unwrap_or(name);
+
+        // Escaped characters (e.g., `\%`)
+        let mut chars = name.chars();
+        if let (Some(c @ ('%' | '&' | '$' | '#' | '_' | '{' | '}')), None) =
+            (chars.next(), chars.next())
+        {
+            self.push_until(node.start_byte());
+            self.start.get_or_insert(node.start_byte());
+            self.replace(c, node.byte_range().len());
+            self.position = node.end_byte();
+            return;
+        }
+
+        // The grammar does not know where inline verbatim text ends (e.g., `\verb|text|`)
+        if name == "verb" || name == "verb*" {
+            let rest = self.source.get(node.end_byte()..).unwrap_or_default();
+            let end = rest.chars().next().and_then(|delimiter| {
+                let len = delimiter.len_utf8();
+                Some(node.end_byte() + len + rest.get(len..)?.find(delimiter)? + len)
+            });
+            self.mask(node, end.unwrap_or(node.end_byte()));
+            return;
+        }
+
+        if TEXT_COMMANDS.contains(&name) {
+            self.command(node);
+        } else if NOTE_COMMANDS.contains(&name) {
+            self.note(node);
+        } else {
+            self.mask(node, node.end_byte());
+        }
+    }
+}
+
+/// Parser for LaTeX that returns the paragraphs and the titles of the body of the document
+///
+/// Commands, formulas, math environments, verbatim text, and labels or references are ignored.
+struct ParsedLatex {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedLatex {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let source = std::str::from_utf8(text.as_ref())?;
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter::Language::new(tree_sitter_latex::LANGUAGE))?;
+        let Some(tree) = parser.parse(source, None) else {
+            anyhow::bail!("Invalid language");
+        };
+
+        // Without a `document` environment (e.g., a chapter included from another file),
+        // the whole file is the body
+        let root = tree.root_node();
+        let body = PreorderTraversal::from(&tree)
+            .find(|node| {
+                node.kind() == "generic_environment"
+                    && node
+                        .child_by_field_name("begin")
+                        .and_then(|begin| begin.child_by_field_name("name"))
+                        .and_then(|name| name.utf8_text(source.as_bytes()).ok())
+                        == Some("{document}")
+            })
+            .unwrap_or(root);
+
+        let mut builder = Builder::new(source);
+        builder.position = body.start_byte();
+        builder.visit(body);
+        builder.flush(Kind::Text);
+        let mut lintables = builder.lintables;
+        lintables.sort_by_key(Lintable::offset);
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedLatex {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for LaTeX
+    pub fn latex() -> Self {
+        Self {
+            name: "latex",
+            detections: &["*.tex"],
+            parser: Mode::Custom(Box::new(move |text| Ok(Box::new(ParsedLatex::new(text)?)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::{Kind, LintableString};
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "latex"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "latex",
+            Language::from_filename(OsStr::new("paper.tex"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let latex = r"\documentclass{article}
+\title{Not checked in the preamble}
+\begin{document}
+\section{Introduction}\label{sec:intro}
+This is \emph{important}, see Section~\ref{sec:intro} and $x^2$ in \cite{knuth}.\footnote{A note on its own.}
+% A comment that is not checked
+It costs 5\% with \verb|raw text| inside.
+
+\begin{equation}
+a = b
+\end{equation}
+\begin{verbatim}
+Not checked either.
+\end{verbatim}
+\begin{itemize}
+\item First item of the list
+\end{itemize}
+\end{document}
+";
+        let source = SharedSource::new("paper.tex", latex.as_bytes().to_vec());
+        let mut parsed = Language::latex().parse(&source).unwrap();
+        let lintables = parsed
+            .lintables(source.as_ref())
+            .map(|lintable| (lintable.as_str().to_owned(), lintable.kind()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lintables,
+            [
+                ("Introduction".into(), Kind::Heading),
+                (
+                    "This is important, see Section\u{A0}\u{FFFC} and \u{FFFC} in \u{FFFC}.\u{FFFC}\nIt costs 5% with \u{FFFC} inside."
+                        .into(),
+                    Kind::Text
+                ),
+                ("A note on its own.".into(), Kind::Text),
+                ("First item of the list".into(), Kind::Text),
+            ]
+        );
+
+        let mut parsed = Language::latex().parse(&source).unwrap();
+        let strings = parsed.strings(source.as_ref()).collect::<Vec<_>>();
+        assert_eq!(
+            strings.first(),
+            Some(&LintableString::new(
+                latex.find("Introduction").unwrap(),
+                "Introduction"
+            ))
+        );
+    }
+
+    #[test]
+    fn replacements() {
+        let latex = r"Pages 1--5 of the book~! It works --- most of the ``time''.";
+        let source = SharedSource::new("chapter.tex", latex.as_bytes().to_vec());
+        let mut parsed = Language::latex().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+        let lintable = lintables.pop().unwrap();
+        assert!(lintables.is_empty());
+        assert_eq!(
+            lintable.as_str(),
+            "Pages 1–5 of the book\u{A0}! It works — most of the “time”."
+        );
+
+        let en_dash = "Pages 1".len();
+        assert_eq!(
+            lintable.source_span((en_dash, '–'.len_utf8()).into()),
+            (latex.find("--").unwrap(), 2).into()
+        );
+        let space = "Pages 1–5 of the book".len();
+        assert_eq!(
+            lintable.source_span((space, '\u{A0}'.len_utf8()).into()),
+            (latex.find('~').unwrap(), 1).into()
+        );
+        let em_dash = "Pages 1–5 of the book\u{A0}! It works ".len();
+        assert_eq!(
+            lintable.source_span((em_dash, '—'.len_utf8()).into()),
+            (latex.find("---").unwrap(), 3).into()
+        );
+        let quote = "Pages 1–5 of the book\u{A0}! It works — most of the “time".len();
+        assert_eq!(
+            lintable.source_span((quote, '”'.len_utf8()).into()),
+            (latex.find("''").unwrap(), 2).into()
+        );
+    }
+}
@@ -8,7 +8,39 @@ use tree_sitter_md::MarkdownTree;
 
 use crate::tree::PreorderTraversal;
 
-use super::{Language, LintableNode, Mode, Parsed};
+use super::{
+    Kind, Language, Lintable, LintableNode, LintableString, Mode, PLACEHOLDER, Parsed, Replacement,
+};
+
+/// Builds a single lintable string from a heading, with its code spans and images replaced by a placeholder,
+/// so it can be checked as a whole (e.g., its end or the case of its words)
+fn heading(node: &LintableNode<'_>, source: &[u8]) -> Lintable {
+    let range = node.byte_range();
+    let mut value = String::new();
+    let mut replacements = Vec::new();
+    let mut position = range.start;
+    // An empty range at the end masks what is ignored after the last lintable range
+    let lintable_ranges = node
+        .lintable_ranges()
+        .chain(std::iter::once(range.end..range.end));
+    for lintable_range in lintable_ranges {
+        if lintable_range.start > position {
+            replacements.push(Replacement {
+                index: value.len(),
+                len: PLACEHOLDER.len_utf8(),
+                source_len: lintable_range.start - position,
+            });
+            value.push(PLACEHOLDER);
+        }
+        position = lintable_range.end;
+        value.push_str(&String::from_utf8_lossy(
+            source.get(lintable_range).unwrap_or_default(),
+        ));
+    }
+
+    Lintable::new(LintableString::new(range.start, value), Kind::Heading)
+        .with_replacements(replacements)
+}
 
 /// Parser for Markdown that helps to ignore text in code span
 struct ParsedMarkdown {
@@ -34,6 +66,24 @@ impl Parsed for ParsedMarkdown {
     fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
         Box::new(IterMarkdown::new(self))
     }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        let heading_ranges = PreorderTraversal::from(self.tree.block_tree())
+            .filter(|node| ["atx_heading", "setext_heading"].contains(&node.kind()))
+            .map(|node| node.byte_range())
+            .collect::<RangeSet<_>>();
+
+        Box::new(self.lintable_nodes().flat_map(move |node| {
+            let start = node.byte_range().start;
+            if heading_ranges.intersects(start..start + 1) {
+                vec![heading(&node, source)]
+            } else {
+                node.lintable_strings(source)
+                    .map(|string| Lintable::new(string, Kind::Text))
+                    .collect()
+            }
+        }))
+    }
 }
 
 type MarkdownTraversal<'t> = FlatMap<
@@ -118,7 +168,7 @@ mod tests {
     use std::ffi::OsStr;
 
     use crate::SharedSource;
-    use crate::lang::LintableString;
+    use crate::lang::{Kind, LintableString};
 
     use super::Language;
 
@@ -176,6 +226,59 @@ hello
         );
     }
 
+    #[test]
+    fn headings() {
+        let markdown = r"# Hello
+
+Some text
+
+Other heading
+-------------
+";
+        let markdown = SharedSource::new("file.md", markdown.as_bytes().to_vec());
+        let mut parsed = Language::markdown().parse(&markdown).unwrap();
+        let kinds = parsed
+            .lintables(markdown.as_ref())
+            .map(|lintable| (lintable.as_str().to_owned(), lintable.kind()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                ("Hello".into(), Kind::Heading),
+                ("Some text".into(), Kind::Text),
+                ("Other heading".into(), Kind::Heading),
+            ]
+        );
+    }
+
+    #[test]
+    fn heading_with_code_span() {
+        let markdown = "# Hello. `foo` With Bar\n\nSome `code` here\n";
+        let markdown = SharedSource::new("file.md", markdown.as_bytes().to_vec());
+        let mut parsed = Language::markdown().parse(&markdown).unwrap();
+        let lintables = parsed.lintables(markdown.as_ref()).collect::<Vec<_>>();
+        let kinds = lintables
+            .iter()
+            .map(|lintable| (lintable.as_str(), lintable.kind()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                ("Hello. \u{FFFC} With Bar", Kind::Heading),
+                ("Some ", Kind::Text),
+                (" here", Kind::Text),
+            ]
+        );
+
+        let heading = lintables.first().unwrap();
+        let with = heading.as_str().find("With").unwrap();
+        assert_eq!(heading.source_span((with, 4).into()), (15, 4).into());
+        assert_eq!(
+            heading.source_span((with - 4, 3).into()),
+            (9, "`foo`".len()).into()
+        );
+    }
+
     #[test]
     fn image() {
         let markdown = r"abc ![link](link)";
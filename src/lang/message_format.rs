@@ -0,0 +1,305 @@
+use crate::config::MessageFormat;
+
+use super::{Kind, Lintable, LintableString, PLACEHOLDER, Replacement};
+
+/// Types of ICU arguments whose branches are messages (e.g., `{count, plural, one {# file} other {# files}}`)
+const BRANCHING_TYPES: &[&str] = &["plural", "select", "selectordinal"];
+
+/// Literal text of a message, along with its index within the string it has been found in
+struct Literal {
+    index: usize,
+    value: String,
+    replacements: Vec<Replacement>,
+}
+
+/// Position within an ICU message along with the literal text found so far
+struct IcuTokenizer<'s> {
+    text: &'s str,
+    position: usize,
+    literals: Vec<Literal>,
+}
+
+impl<'s> IcuTokenizer<'s> {
+    fn rest(&self) -> &'s str {
+        self.text.get(self.position..).unwrap_or_default()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes a message, with its arguments replaced so that only the literal text is checked
+    ///
+    /// A nested message (i.e., a branch of an argument) stops before its closing `}`, and `#`
+    /// stands for the number in a branch of a plural argument.
+    ///
+    /// Returns `None` if the message is invalid.
+    fn message(&mut self, is_nested: bool, is_plural: bool) -> Option<()> {
+        let index = self.position;
+        let mut value = String::new();
+        let mut replacements = Vec::new();
+        let mut is_quoted = false;
+        while let Some(c) = self.rest().chars().next() {
+            let next = self.rest().chars().nth(1);
+            match c {
+                '\'' if next == Some('\'') => {
+                    replacements.push(Replacement {
+                        index: value.len(),
+                        len: 1,
+                        source_len: 2,
+                    });
+                    value.push('\'');
+                    self.position += 2;
+                    continue;
+                }
+                '\'' if is_quoted
+                    || matches!(next, Some('{' | '}' | '|'))
+                    || (is_plural && next == Some('#')) =>
+                {
+                    // The apostrophes around quoted syntax characters (e.g., `'{'`) are removed
+                    is_quoted = !is_quoted;
+                    replacements.push(Replacement {
+                        index: value.len(),
+                        len: 0,
+                        source_len: 1,
+                    });
+                    self.position += 1;
+                    continue;
+                }
+                _ if is_quoted => {}
+                '{' => {
+                    let start = self.position;
+                    self.argument()?;
+                    replacements.push(Replacement {
+                        index: value.len(),
+                        len: PLACEHOLDER.len_utf8(),
+                        source_len: self.position - start,
+                    });
+                    value.push(PLACEHOLDER);
+                    continue;
+                }
+                '}' if is_nested => break,
+                '}' => return None,
+                '#' if is_plural => {
+                    replacements.push(Replacement {
+                        index: value.len(),
+                        len: PLACEHOLDER.len_utf8(),
+                        source_len: 1,
+                    });
+                    value.push(PLACEHOLDER);
+                    self.position += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            value.push(c);
+            self.position += c.len_utf8();
+        }
+        if is_nested && self.rest().is_empty() {
+            return None;
+        }
+
+        self.literals.push(Literal {
+            index,
+            value,
+            replacements,
+        });
+
+        Some(())
+    }
+
+    /// Consumes an argument (e.g., `{name}`, `{count, number}`, or `{count, plural, ...}`),
+    /// including the branches whose messages are checked separately
+    fn argument(&mut self) -> Option<()> {
+        self.position += 1;
+        let rest = self.rest();
+        let end = rest.find([',', '}'])?;
+        let mut fields = rest.get(end..)?;
+        self.position += end;
+        if fields.starts_with('}') {
+            self.position += 1;
+            return Some(());
+        }
+
+        self.position += 1;
+        fields = fields.get(1..)?;
+        let type_end = fields.find([',', '}'])?;
+        let argument_type = fields.get(..type_end)?.trim();
+        let is_branching = BRANCHING_TYPES.contains(&argument_type);
+        self.position += type_end;
+        if is_branching && fields.get(type_end..)?.starts_with(',') {
+            self.position += 1;
+            return self.branches(argument_type != "select");
+        }
+
+        // The style of the argument (e.g., `::currency/EUR`) is skipped
+        let mut depth = 0;
+        while let Some(c) = self.rest().chars().next() {
+            self.position += c.len_utf8();
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return Some(()),
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Consumes the branches of an argument (e.g., `one {# file} other {# files}`)
+    /// until the end of the argument
+    fn branches(&mut self, is_plural: bool) -> Option<()> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with('}') {
+                self.position += 1;
+                return Some(());
+            }
+
+            // The selector (e.g., `one`, `=0`, or `offset:1`)
+            let selector_len = rest
+                .find(|c: char| c.is_whitespace() || c == '{' || c == '}')
+                .unwrap_or(rest.len());
+            self.position += selector_len;
+            self.skip_whitespace();
+            if self.rest().starts_with('{') {
+                self.position += 1;
+                self.message(true, is_plural)?;
+                self.position += 1;
+            } else if selector_len == 0 {
+                return None;
+            }
+        }
+    }
+}
+
+impl Lintable {
+    /// Splits a message written with the given syntax into its literal text
+    ///
+    /// The arguments (e.g., `{name}` in ICU MessageFormat) are replaced by a placeholder,
+    /// and the text of each branch (e.g., `{# files}` in a plural argument) is split into its own string.
+    /// The string is returned unchanged if it is not a valid message, if it is a comment,
+    /// or if it has been decoded from its source (e.g., with escape sequences).
+    pub fn split_message(&self, format: MessageFormat) -> Vec<Self> {
+        if self.kind == Kind::Comment || !self.replacements.is_empty() {
+            return vec![self.clone()];
+        }
+
+        let literals = match format {
+            MessageFormat::Icu => {
+                let mut tokenizer = IcuTokenizer {
+                    text: self.as_str(),
+                    position: 0,
+                    literals: Vec::new(),
+                };
+                if tokenizer.message(false, false).is_none() {
+                    return vec![self.clone()];
+                }
+                tokenizer.literals
+            }
+        };
+
+        let mut lintables = literals
+            .into_iter()
+            .filter(|literal| literal.value.trim().len() > 3)
+            .map(|literal| Self {
+                string: LintableString::new(self.offset() + literal.index, literal.value),
+                kind: self.kind,
+                locale: self.locale.clone(),
+                fuzzy: self.fuzzy,
+                replacements: literal.replacements,
+                encoding: self.encoding,
+            })
+            .collect::<Vec<_>>();
+        lintables.sort_by_key(Self::offset);
+
+        lintables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::MessageFormat;
+    use crate::lang::{Lintable, LintableString};
+
+    fn split(message: &str) -> Vec<Lintable> {
+        Lintable::from(LintableString::new(10, message)).split_message(MessageFormat::Icu)
+    }
+
+    fn strings(message: &str) -> Vec<(usize, String)> {
+        split(message)
+            .into_iter()
+            .map(|lintable| (lintable.offset() - 10, lintable.as_str().to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            strings("Hello {name}, you have {count, number} new messages!"),
+            [(0, "Hello \u{FFFC}, you have \u{FFFC} new messages!".into())]
+        );
+        assert_eq!(
+            strings("Sent on {date, date, ::yyyyMMdd} at {time, time, short}"),
+            [(0, "Sent on \u{FFFC} at \u{FFFC}".into())]
+        );
+    }
+
+    #[test]
+    fn branches() {
+        let message = "You have {count, plural, =0 {no files} one {# file} other {# files}} here.";
+        assert_eq!(
+            strings(message),
+            [
+                (0, "You have \u{FFFC} here.".into()),
+                (message.find("no files").unwrap(), "no files".into()),
+                (message.find("# file}").unwrap(), "\u{FFFC} file".into()),
+                (message.find("# files").unwrap(), "\u{FFFC} files".into()),
+            ]
+        );
+
+        let message =
+            "{gender, select, female {She replied} male {He replied} other {They replied}}";
+        assert_eq!(
+            strings(message),
+            [
+                (message.find("She").unwrap(), "She replied".into()),
+                (message.find("He ").unwrap(), "He replied".into()),
+                (message.find("They").unwrap(), "They replied".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn quotes() {
+        let message = "It''s a '{literal}' brace";
+        let mut lintables = split(message);
+        let lintable = lintables.pop().unwrap();
+        assert!(lintables.is_empty());
+        assert_eq!(lintable.as_str(), "It's a {literal} brace");
+
+        let apostrophe = "It".len();
+        assert_eq!(
+            lintable.source_span((apostrophe, 1).into()),
+            (10 + apostrophe, 2).into()
+        );
+        let brace = "It's a ".len();
+        assert_eq!(
+            lintable.source_span((brace, 1).into()),
+            (10 + message.find('{').unwrap(), 1).into()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            strings("Not a {valid message"),
+            [(0, "Not a {valid message".into())]
+        );
+        assert_eq!(strings("Closing } brace"), [(0, "Closing } brace".into())]);
+    }
+}
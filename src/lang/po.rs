@@ -0,0 +1,431 @@
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement};
+
+/// Locale of the original strings (`msgid`) of a catalog
+const SOURCE_LOCALE: &str = "en";
+
+/// Field of an entry that is being parsed
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Field {
+    /// `msgctxt`, never checked
+    Context,
+
+    /// `msgid`
+    Id,
+
+    /// `msgid_plural`
+    IdPlural,
+
+    /// `msgstr` or `msgstr[N]`
+    Str,
+}
+
+/// A string of an entry joined from its quoted lines, with its escape sequences decoded
+#[derive(Default)]
+struct Text {
+    offset: usize,
+    /// End of the content of the last quoted line within the source
+    end: usize,
+    value: String,
+    replacements: Vec<Replacement>,
+}
+
+impl Text {
+    /// Appends the content of a quoted line found at the given offset
+    fn push(&mut self, content: &str, offset: usize) {
+        if self.value.is_empty() {
+            self.offset = offset;
+        } else {
+            // The closing quote, the line terminator, and the opening quote of the next line are skipped
+            self.replacements.push(Replacement {
+                index: self.value.len(),
+                len: 0,
+                source_len: offset - self.end,
+            });
+        }
+        decode(content, &mut self.value, &mut self.replacements);
+        self.end = offset + content.len();
+    }
+
+    fn into_lintable(self) -> Option<Lintable> {
+        if self.value.is_empty() {
+            return None;
+        }
+
+        let string = LintableString::new(self.offset, self.value);
+        Some(Lintable::new(string, Kind::Text).with_replacements(self.replacements))
+    }
+}
+
+/// An entry of a catalog along with its strings
+#[derive(Default)]
+struct Entry {
+    fuzzy: bool,
+    obsolete: bool,
+    field: Option<Field>,
+    has_id: bool,
+    has_str: bool,
+    /// `msgid` and `msgid_plural`
+    ids: Vec<Text>,
+    /// `msgstr` or `msgstr[N]`
+    translations: Vec<Text>,
+}
+
+impl Entry {
+    fn is_header(&self) -> bool {
+        self.has_id && self.ids.first().is_none_or(|id| id.value.is_empty()) && !self.obsolete
+    }
+
+    /// Reads the `Language:` field of the header
+    fn language(&self) -> Option<String> {
+        self.translations.first()?.value.lines().find_map(|line| {
+            let language = line.strip_prefix("Language:")?.trim();
+            (!language.is_empty()).then(|| language.to_owned())
+        })
+    }
+
+    /// Starts a new string for the given field
+    fn start(&mut self, field: Field) {
+        self.field = Some(field);
+        match field {
+            Field::Context => {}
+            Field::Id | Field::IdPlural => self.ids.push(Text::default()),
+            Field::Str => self.translations.push(Text::default()),
+        }
+    }
+
+    /// Adds a quoted string found on a line to the current field
+    fn push(&mut self, line: &str, offset: usize) {
+        let Some(field) = self.field else {
+            return;
+        };
+        let Some((content_offset, content)) = quoted(line) else {
+            return;
+        };
+        let text = match field {
+            Field::Context => return,
+            Field::Id | Field::IdPlural => self.ids.last_mut(),
+            Field::Str => self.translations.last_mut(),
+        };
+        if let Some(text) = text {
+            text.push(content, offset + content_offset);
+        }
+    }
+}
+
+/// Returns the content of the quoted string of a line with its offset
+fn quoted(line: &str) -> Option<(usize, &str)> {
+    let start = line.find('"')? + 1;
+    let end = line.rfind('"')?;
+
+    Some((start, line.get(start..end)?))
+}
+
+/// Decodes the escape sequences of a quoted string (e.g., `\n` or `\"`)
+fn decode(content: &str, value: &mut String, replacements: &mut Vec<Replacement>) {
+    let mut i = 0;
+    while let Some(c) = content.get(i..).and_then(|rest| rest.chars().next()) {
+        if c != '\\' {
+            value.push(c);
+            i += c.len_utf8();
+            continue;
+        }
+
+        let Some(escaped) = content.get(i..).and_then(|escape| escape.chars().nth(1)) else {
+            break;
+        };
+        let decoded = match escaped {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            c => c,
+        };
+        replacements.push(Replacement {
+            index: value.len(),
+            len: decoded.len_utf8(),
+            source_len: 1 + escaped.len_utf8(),
+        });
+        value.push(decoded);
+        i += 1 + escaped.len_utf8();
+    }
+}
+
+/// Parser for gettext catalogs (`.po` and `.pot`) that returns the original strings and their translations
+///
+/// The translations are checked with the locale given by the `Language:` field of the header,
+/// while the original strings are expected to be in English.
+struct ParsedPo {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedPo {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(text.as_ref())?;
+
+        let mut entries = Vec::new();
+        let mut entry = Entry::default();
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            let line_offset = offset;
+            offset += line.len();
+
+            let trimmed = line.trim_start();
+            let mut line_offset = line_offset + line.len() - trimmed.len();
+            let mut line = trimmed.trim_end();
+            let mut obsolete = false;
+            if let Some(rest) = line.strip_prefix("#~") {
+                // Previous strings of an obsolete entry (`#~|`) are not checked
+                if rest.starts_with('|') {
+                    continue;
+                }
+                let rest_trimmed = rest.trim_start();
+                line_offset += line.len() - rest_trimmed.len();
+                line = rest_trimmed;
+                obsolete = true;
+            }
+
+            if line.is_empty() {
+                entries.push(std::mem::take(&mut entry));
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if entry.has_str {
+                    entries.push(std::mem::take(&mut entry));
+                }
+                if let Some(flags) = comment.strip_prefix(',') {
+                    entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+                }
+                continue;
+            }
+            if line.starts_with('"') {
+                entry.push(line, line_offset);
+                continue;
+            }
+
+            let keyword = line.split_whitespace().next().unwrap_or_default();
+            let field = match keyword {
+                "msgctxt" => Field::Context,
+                "msgid" => Field::Id,
+                "msgid_plural" => Field::IdPlural,
+                _ if keyword.starts_with("msgstr") => Field::Str,
+                _ => continue,
+            };
+            if matches!(field, Field::Context | Field::Id) && entry.has_str {
+                entries.push(std::mem::take(&mut entry));
+            }
+            entry.obsolete |= obsolete;
+            entry.start(field);
+            entry.has_id |= field == Field::Id;
+            entry.has_str |= field == Field::Str;
+            entry.push(line, line_offset);
+        }
+        entries.push(entry);
+
+        let language = entries
+            .iter()
+            .find(|entry| entry.is_header())
+            .and_then(Entry::language);
+        let lintables = entries
+            .into_iter()
+            .filter(|entry| !entry.is_header())
+            .flat_map(|entry| {
+                let fuzzy = entry.fuzzy || entry.obsolete;
+                let ids = entry
+                    .ids
+                    .into_iter()
+                    .filter_map(Text::into_lintable)
+                    .map(|lintable| lintable.with_locale(Some(SOURCE_LOCALE)));
+                let translations = entry
+                    .translations
+                    .into_iter()
+                    .filter_map(Text::into_lintable)
+                    .map(|lintable| lintable.with_locale(language.as_deref()));
+
+                ids.chain(translations)
+                    .map(move |lintable| if fuzzy { lintable.fuzzy() } else { lintable })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedPo {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for gettext catalogs
+    pub fn po() -> Self {
+        Self {
+            name: "po",
+            detections: &["*.po", "*.pot"],
+            parser: Mode::Custom(Box::new(move |text| Ok(Box::new(ParsedPo::new(text)?)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::LintableString;
+
+    use super::Language;
+
+    const CATALOG: &str = r#"# Translation of the application
+msgid ""
+msgstr ""
+"Project-Id-Version: app 1.0\n"
+"Language: de\n"
+
+#: src/main.rs:10
+msgid "Save the file"
+msgstr "Die Datei speichern"
+
+msgctxt "menu"
+msgid "One file"
+msgid_plural "%d files"
+msgstr[0] "Eine Datei"
+msgstr[1] "%d Dateien"
+
+#, fuzzy, c-format
+msgid ""
+"A long message "
+"on two lines"
+msgstr "Eine \"lange\" Nachricht"
+
+#~ msgid "Old message"
+#~ msgstr "Alte Nachricht"
+"#;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "po"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        for filename in ["de.po", "messages.pot"] {
+            assert_eq!(
+                "po",
+                Language::from_filename(OsStr::new(filename))
+                    .unwrap()
+                    .name()
+            );
+        }
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let po = SharedSource::new("de.po", CATALOG.as_bytes().to_vec());
+        let mut parsed = Language::po().parse(&po).unwrap();
+        let strings = parsed.strings(po.as_ref()).collect::<Vec<_>>();
+        let string = |value: &str| LintableString::new(CATALOG.find(value).unwrap(), value);
+        assert_eq!(
+            strings,
+            [
+                string("Save the file"),
+                string("Die Datei speichern"),
+                string("One file"),
+                string("%d files"),
+                string("Eine Datei"),
+                string("%d Dateien"),
+                LintableString::new(
+                    CATALOG.find("A long message ").unwrap(),
+                    "A long message on two lines"
+                ),
+                LintableString::new(
+                    CATALOG.find("Eine \\\"").unwrap(),
+                    "Eine \"lange\" Nachricht"
+                ),
+                string("Old message"),
+                string("Alte Nachricht"),
+            ]
+        );
+    }
+
+    #[test]
+    fn locale_and_fuzzy() {
+        let po = SharedSource::new("de.po", CATALOG.as_bytes().to_vec());
+        let mut parsed = Language::po().parse(&po).unwrap();
+        let lintables = parsed
+            .lintables(po.as_ref())
+            .map(|lintable| {
+                (
+                    lintable.as_str().to_owned(),
+                    lintable.locale().map(ToOwned::to_owned),
+                    lintable.is_fuzzy(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let lintable = |value: &str| lintables.iter().find(|(v, _, _)| v == value).unwrap();
+
+        assert_eq!(
+            lintable("Save the file"),
+            &("Save the file".into(), Some("en".into()), false)
+        );
+        assert_eq!(
+            lintable("Eine Datei"),
+            &("Eine Datei".into(), Some("de".into()), false)
+        );
+        assert!(lintable("A long message on two lines").2);
+        assert!(lintable("Eine \"lange\" Nachricht").2);
+        assert!(lintable("Alte Nachricht").2);
+    }
+
+    #[test]
+    fn source_span() {
+        let po = SharedSource::new("de.po", CATALOG.as_bytes().to_vec());
+        let mut parsed = Language::po().parse(&po).unwrap();
+        let lintables = parsed.lintables(po.as_ref()).collect::<Vec<_>>();
+
+        let message = lintables
+            .iter()
+            .find(|lintable| lintable.as_str() == "A long message on two lines")
+            .unwrap();
+        assert_eq!(
+            message.source_span((15, 2).into()),
+            (CATALOG.find("on two").unwrap(), 2).into()
+        );
+
+        let quote = lintables
+            .iter()
+            .find(|lintable| lintable.as_str() == "Eine \"lange\" Nachricht")
+            .unwrap();
+        assert_eq!(
+            quote.source_span((5, 7).into()),
+            (CATALOG.find("\\\"lange").unwrap(), 9).into()
+        );
+    }
+
+    #[test]
+    fn without_language() {
+        let catalog = "msgid \"Save the file\"\nmsgstr \"\"\n";
+        let pot = SharedSource::new("messages.pot", catalog.as_bytes().to_vec());
+        let mut parsed = Language::po().parse(&pot).unwrap();
+        let mut lintables = parsed.lintables(pot.as_ref()).collect::<Vec<_>>();
+        let lintable = lintables.pop().unwrap();
+        assert_eq!(lintable.as_str(), "Save the file");
+        assert_eq!(lintable.offset(), 7);
+        assert!(lintables.is_empty());
+    }
+}
@@ -0,0 +1,336 @@
+use super::{
+    Encoding, Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement,
+};
+
+/// Characters that separate a key from its value, along with `=` and `:`
+const WHITESPACES: &[char] = &[' ', '\t', '\u{C}'];
+
+/// Returns the index of the value of a line (e.g., `value` in `key = value`)
+fn value_start(line: &str) -> usize {
+    let mut chars = line.char_indices();
+    let mut key_end = line.len();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '=' | ':' => {
+                key_end = i;
+                break;
+            }
+            c if WHITESPACES.contains(&c) => {
+                key_end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let rest = line.get(key_end..).unwrap_or_default();
+    let separator = rest.trim_start_matches(WHITESPACES);
+    let value = separator
+        .strip_prefix(['=', ':'])
+        .unwrap_or(separator)
+        .trim_start_matches(WHITESPACES);
+
+    line.len() - value.len()
+}
+
+/// Parses the 4 hexadecimal digits of a `\uXXXX` escape sequence
+fn code_unit(hex: Option<&str>) -> Option<u32> {
+    let hex = hex?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// Decodes a `\uXXXX` escape sequence at the start of a string, along with its length,
+/// including the pairs of surrogates (e.g., `😀`)
+fn unicode_escape(escape: &str) -> Option<(char, usize)> {
+    let high = code_unit(escape.get(2..6))?;
+    if (0xD800..0xDC00).contains(&high) {
+        let low = code_unit(
+            escape
+                .get(8..12)
+                .filter(|_| escape.get(6..8) == Some("\\u")),
+        )?;
+        let c = char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low.checked_sub(0xDC00)?))?;
+        return Some((c, 12));
+    }
+
+    Some((char::from_u32(high)?, 6))
+}
+
+/// Returns the length of a string within the source, which is encoded in UTF-8 or in ISO-8859-1
+fn source_len(text: &str, latin1: bool) -> usize {
+    if latin1 {
+        text.chars().count()
+    } else {
+        text.len()
+    }
+}
+
+/// Decodes the escape sequences of a line of a value
+///
+/// Returns whether the value continues on the next line (i.e., the line ends with a `\`).
+fn decode(
+    line: &str,
+    latin1: bool,
+    value: &mut String,
+    replacements: &mut Vec<Replacement>,
+) -> bool {
+    let mut i = 0;
+    while let Some(c) = line.get(i..).and_then(|rest| rest.chars().next()) {
+        if c != '\\' {
+            // A character of the source encoded in ISO-8859-1 takes a single byte
+            if latin1 && !c.is_ascii() {
+                replacements.push(Replacement {
+                    index: value.len(),
+                    len: c.len_utf8(),
+                    source_len: 1,
+                });
+            }
+            value.push(c);
+            i += c.len_utf8();
+            continue;
+        }
+
+        let escape = line.get(i..).unwrap_or_default();
+        let Some(escaped) = escape.chars().nth(1) else {
+            return true;
+        };
+        let (decoded, source_len) = match escaped {
+            'u' => unicode_escape(escape).unwrap_or(('u', 2)),
+            't' => ('\t', 2),
+            'n' => ('\n', 2),
+            'r' => ('\r', 2),
+            'f' => ('\u{C}', 2),
+            c => (c, 1 + c.len_utf8()),
+        };
+        replacements.push(Replacement {
+            index: value.len(),
+            len: decoded.len_utf8(),
+            source_len,
+        });
+        value.push(decoded);
+        i += source_len;
+    }
+
+    false
+}
+
+/// Parser for Java properties files (e.g., resource bundles) that returns the values with their escape sequences decoded
+///
+/// Files that are not encoded in UTF-8 are decoded as ISO-8859-1, the historical encoding of the format,
+/// and the characters outside of ASCII of their fixes are written as escape sequences.
+struct ParsedProperties {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedProperties {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let bytes = text.as_ref();
+        let (text, latin1) = match std::str::from_utf8(bytes) {
+            Ok(text) => (text.into(), false),
+            Err(_) => (
+                bytes.iter().copied().map(char::from).collect::<String>(),
+                true,
+            ),
+        };
+
+        let mut lintables = Vec::new();
+        let mut lines = text.split_inclusive('\n').scan(0, |offset, line| {
+            let start = *offset;
+            *offset += source_len(line, latin1);
+            Some((start, line))
+        });
+        while let Some((offset, line)) = lines.next() {
+            let content = line.trim_start_matches(WHITESPACES);
+            let mut offset = offset + line.len() - content.len();
+            if content.trim_end().is_empty() || content.starts_with(['#', '!']) {
+                continue;
+            }
+
+            let start = value_start(content.trim_end_matches(['\n', '\r']));
+            offset += source_len(content.get(..start).unwrap_or_default(), latin1);
+            let mut line = content.get(start..).unwrap_or_default();
+            let mut value = String::new();
+            let mut replacements = Vec::new();
+            loop {
+                let body = line.trim_end_matches(['\n', '\r']);
+                if !decode(body, latin1, &mut value, &mut replacements) {
+                    break;
+                }
+                let Some((_, next_line)) = lines.next() else {
+                    break;
+                };
+
+                // The `\`, the line terminator, and the indentation of the next line are skipped
+                let next_content = next_line.trim_start_matches(WHITESPACES);
+                replacements.push(Replacement {
+                    index: value.len(),
+                    len: 0,
+                    source_len: 1 + line.len() - body.len() + next_line.len() - next_content.len(),
+                });
+                line = next_content;
+            }
+
+            if value.trim().len() > 3 {
+                let string = LintableString::new(offset, value);
+                let lintable = Lintable::new(string, Kind::Text).with_replacements(replacements);
+                lintables.push(if latin1 {
+                    // A fix must not write characters encoded in UTF-8 in the file
+                    lintable.with_encoding(Encoding::UnicodeEscapes)
+                } else {
+                    lintable
+                });
+            }
+        }
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedProperties {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for Java properties files
+    pub fn properties() -> Self {
+        Self {
+            name: "properties",
+            detections: &["*.properties"],
+            parser: Mode::Custom(Box::new(move |text| {
+                Ok(Box::new(ParsedProperties::new(text)?))
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::LintableString;
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "properties"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "properties",
+            Language::from_filename(OsStr::new("Messages_fr.properties"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let properties = r"# A comment that is not checked
+! Another comment
+greeting = Hello, World!
+error.missing:The file is missing
+key\ with\ spaces  Some value
+long.message = A message \
+    on two lines
+unicode = Café ouvert
+empty =
+";
+        let source = SharedSource::new("Messages.properties", properties.as_bytes().to_vec());
+        let mut parsed = Language::properties().parse(&source).unwrap();
+        let strings = parsed.strings(source.as_ref()).collect::<Vec<_>>();
+        let string =
+            |value: &str, start: &str| LintableString::new(properties.find(start).unwrap(), value);
+        assert_eq!(
+            strings,
+            [
+                string("Hello, World!", "Hello"),
+                string("The file is missing", "The file"),
+                string("Some value", "Some"),
+                string("A message on two lines", "A message"),
+                string("Café ouvert", "Caf"),
+            ]
+        );
+    }
+
+    #[test]
+    fn source_spans() {
+        let properties =
+            "long = A message \\\n    on two lines\nunicode = Caf\\u00e9 ouvert \\uD83D\\uDE00!\n";
+        let source = SharedSource::new("Messages.properties", properties.as_bytes().to_vec());
+        let mut parsed = Language::properties().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+
+        let unicode = lintables.pop().unwrap();
+        assert_eq!(unicode.as_str(), "Café ouvert 😀!");
+        let e = "Caf".len();
+        assert_eq!(
+            unicode.source_span((e, 'é'.len_utf8()).into()),
+            (properties.find("\\u00e9").unwrap(), 6).into()
+        );
+        let mark = "Café ouvert 😀".len();
+        assert_eq!(
+            unicode.source_span((mark, 1).into()),
+            (properties.rfind('!').unwrap(), 1).into()
+        );
+
+        let long = lintables.pop().unwrap();
+        assert_eq!(long.as_str(), "A message on two lines");
+        let on = "A message ".len();
+        assert_eq!(
+            long.source_span((on, 2).into()),
+            (properties.find("on two").unwrap(), 2).into()
+        );
+        assert!(lintables.is_empty());
+    }
+
+    #[test]
+    fn latin1() {
+        let properties = b"caf\xe9 = Caf\xe9 ouvert \\\n  le dimanche!\n";
+        let source = SharedSource::new("Messages.properties", properties.to_vec());
+        let mut parsed = Language::properties().parse(&source).unwrap();
+        let mut lintables = parsed.lintables(source.as_ref()).collect::<Vec<_>>();
+
+        let lintable = lintables.pop().unwrap();
+        assert!(lintables.is_empty());
+        assert_eq!(lintable.as_str(), "Caf\u{e9} ouvert le dimanche!");
+        assert_eq!(lintable.offset(), 7);
+        let e = "Caf".len();
+        assert_eq!(
+            lintable.source_span((e, '\u{e9}'.len_utf8()).into()),
+            (10, 1).into()
+        );
+        let mark = lintable.as_str().find('!').unwrap();
+        assert_eq!(
+            lintable.source_span((mark, 1).into()),
+            (properties.len() - 2, 1).into()
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use std::ops::Range;
+
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed};
+
+/// Characters that start an item of a bulleted list
+const BULLETS: &[char] = &['-', '*', '+', '•', '‣', '⁃'];
+
+/// Characters after which inline markup can start (e.g., `(` in `(|version|)`)
+const MARKUP_PRECEDERS: &str = "([{<'\"-/";
+
+/// A line of the source without its line terminator
+#[derive(Clone, Copy)]
+struct Line<'s> {
+    offset: usize,
+    text: &'s str,
+}
+
+impl Line<'_> {
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    fn indentation(&self) -> usize {
+        self.text.len() - self.text.trim_start().len()
+    }
+
+    fn content(&self) -> &str {
+        self.text.trim()
+    }
+
+    /// Whether the line only repeats a punctuation character (e.g., the underline of a title like `=====`)
+    fn is_adornment(&self) -> bool {
+        let text = self.text.trim_end();
+        let mut chars = text.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+
+        first.is_ascii_punctuation() && text.len() >= 2 && chars.all(|c| c == first)
+    }
+
+    /// Whether the line is a border of a simple table (e.g., `=====  =====`)
+    fn is_table_border(&self) -> bool {
+        let content = self.content();
+        content.starts_with('=')
+            && content.contains(' ')
+            && content.chars().all(|c| c == '=' || c == ' ')
+    }
+}
+
+/// Returns the length of the marker of a list item or of a field at the start of a line
+/// (e.g., `- `, `1. `, `(2) `, or `:param name: `), including the spaces that follow it
+fn marker_len(content: &str) -> usize {
+    let marker = if content.starts_with(BULLETS) {
+        content.chars().next().map_or(0, char::len_utf8)
+    } else if let Some(field) = content.strip_prefix(':') {
+        field
+            .find(':')
+            .filter(|end| *end > 0)
+            .map_or(0, |end| end + 2)
+    } else {
+        let enumerator = content.strip_prefix('(').unwrap_or(content);
+        let len = enumerator
+            .find(|c: char| !c.is_ascii_digit() && c != '#')
+            .unwrap_or(enumerator.len());
+        let suffix = enumerator.get(len..).unwrap_or_default();
+        if len > 0 && suffix.starts_with(['.', ')']) {
+            content.len() - suffix.len() + 1
+        } else {
+            0
+        }
+    };
+
+    // A role at the start of a paragraph (e.g., `:func:`) is followed by its text, not by a space
+    let rest = content.get(marker..).unwrap_or_default();
+    if marker == 0 || !(rest.is_empty() || rest.starts_with(' ')) {
+        return 0;
+    }
+
+    content.len() - rest.trim_start().len()
+}
+
+/// Returns the length of a role before or after interpreted text (e.g., `:func:` in ``:func:`main` ``)
+fn role_len(text: &str) -> Option<usize> {
+    let name = text.strip_prefix(':')?;
+    let end = name.find(':')?;
+    let is_name = end > 0
+        && name
+            .get(..end)?
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.+-".contains(c));
+
+    is_name.then_some(end + 2)
+}
+
+/// Returns the length of the `_` or `__` that follows a reference (e.g., in `` `link`_ ``)
+fn reference_suffix_len(text: &str) -> usize {
+    if text.starts_with("__") {
+        2
+    } else if text.starts_with('_') {
+        1
+    } else {
+        0
+    }
+}
+
+/// Returns the length of the inline markup at the start of a string whose text is not meant to be checked
+fn markup_len(text: &str, is_word_start: bool) -> Option<usize> {
+    if let Some(literal) = text.strip_prefix("``") {
+        return Some(literal.find("``")? + 4);
+    }
+
+    // Interpreted text (e.g., ``:func:`main` `` or `` `link <https://example.com>`_ ``)
+    let role = if text.starts_with(':') {
+        role_len(text)?
+    } else {
+        0
+    };
+    if let Some(interpreted) = text.get(role..).and_then(|text| text.strip_prefix('`')) {
+        let len = role + interpreted.find('`')? + 2;
+        let rest = text.get(len..).unwrap_or_default();
+        let suffix = match reference_suffix_len(rest) {
+            0 if role == 0 => role_len(rest)
+                .filter(|role| !rest.get(*role..).unwrap_or_default().starts_with('`'))
+                .unwrap_or(0),
+            suffix => suffix,
+        };
+        return Some(len + suffix);
+    }
+
+    // Substitution reference (e.g., `|version|`)
+    if let Some(name) = text.strip_prefix('|') {
+        if !is_word_start || name.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let len = name.find('|')? + 2;
+        return Some(len + reference_suffix_len(text.get(len..).unwrap_or_default()));
+    }
+
+    // Footnote or citation reference (e.g., `[1]_`)
+    let label = text.strip_prefix('[')?;
+    let end = label.find(']')?;
+    if label.get(..end)?.contains(char::is_whitespace) {
+        return None;
+    }
+    label.get(end + 1..)?.starts_with('_').then_some(end + 3)
+}
+
+/// Returns the ranges of a paragraph that can be checked, without its inline literals, roles,
+/// and references
+fn text_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    let mut previous: Option<char> = None;
+    while let Some(c) = text.get(i..).and_then(|rest| rest.chars().next()) {
+        let is_word_start =
+            previous.is_none_or(|c| c.is_whitespace() || MARKUP_PRECEDERS.contains(c));
+        let markup = "`:|["
+            .contains(c)
+            .then(|| markup_len(text.get(i..)?, is_word_start))
+            .flatten();
+        if let Some(len) = markup {
+            ranges.push(start..i);
+            i += len;
+            start = i;
+            previous = text.get(..i).and_then(|text| text.chars().next_back());
+            continue;
+        }
+
+        previous = Some(c);
+        i += c.len_utf8();
+    }
+    ranges.push(start.min(text.len())..text.len());
+
+    ranges
+}
+
+/// Parser for reStructuredText that returns the paragraphs and the titles
+///
+/// Literal blocks (after `::`), doctest blocks, tables, and explicit markup (e.g., directives,
+/// comments, or substitution definitions) are ignored, as well as inline literals and roles.
+struct ParsedRst {
+    lintables: Vec<Lintable>,
+}
+
+impl ParsedRst {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(text.as_ref())?;
+        let lines = text
+            .split_inclusive('\n')
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line.len();
+                Some(Line {
+                    offset: start,
+                    text: line.trim_end_matches(['\n', '\r']),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut lintables = Vec::new();
+        let mut push = |offset: usize, text: &str, kind: Kind| {
+            for range in text_ranges(text) {
+                let Some(value) = text.get(range.clone()) else {
+                    continue;
+                };
+                if value.trim().len() <= 3 {
+                    continue;
+                }
+                let string = LintableString::new(offset + range.start, value);
+                lintables.push(Lintable::new(string, kind));
+            }
+        };
+        // Index of the first line that follows the lines that are blank or indented more than the given indentation
+        let block_end = |start: usize, indentation: usize| {
+            lines
+                .iter()
+                .skip(start)
+                .position(|line| !line.is_blank() && line.indentation() <= indentation)
+                .map_or(lines.len(), |len| start + len)
+        };
+
+        let mut i = 0;
+        while let Some(line) = lines.get(i).copied() {
+            let next = lines.get(i + 1).copied();
+            let indentation = line.indentation();
+            let content = line.content();
+            if line.is_blank() {
+                i += 1;
+                continue;
+            }
+
+            // Explicit markup (e.g., `.. note::`, `.. |name| image:: file.png`, or `.. _target:`)
+            if content == ".." || content.starts_with(".. ") {
+                i = block_end(i + 1, indentation);
+                continue;
+            }
+            if content.starts_with(">>>") {
+                i += lines
+                    .iter()
+                    .skip(i)
+                    .take_while(|line| !line.is_blank())
+                    .count();
+                continue;
+            }
+            if content.starts_with("+-") || content.starts_with("+=") || content.starts_with('|') {
+                i += 1;
+                continue;
+            }
+            if line.is_table_border() {
+                let end = lines.iter().enumerate().skip(i + 1).find(|(j, line)| {
+                    line.is_table_border() && lines.get(j + 1).is_none_or(Line::is_blank)
+                });
+                i = end.map_or(lines.len(), |(end, _)| end + 1);
+                continue;
+            }
+
+            // Titles (e.g., `Title` underlined with `=====`, and optionally overlined)
+            if indentation == 0 && line.is_adornment() {
+                let underline = lines.get(i + 2);
+                if let Some(title) = next.filter(|_| {
+                    underline.is_some_and(|underline| {
+                        underline.is_adornment() && underline.text.get(..1) == line.text.get(..1)
+                    })
+                }) {
+                    push(
+                        title.offset + title.indentation(),
+                        title.content(),
+                        Kind::Heading,
+                    );
+                    i += 3;
+                } else {
+                    // A transition
+                    i += 1;
+                }
+                continue;
+            }
+            if indentation == 0
+                && next.is_some_and(|next| next.indentation() == 0 && next.is_adornment())
+            {
+                push(line.offset, line.text.trim_end(), Kind::Heading);
+                i += 2;
+                continue;
+            }
+
+            // Paragraphs, including the items of lists and the bodies of fields
+            let marker = marker_len(content);
+            let text_indentation = indentation + marker;
+            let start = line.offset + text_indentation;
+            let mut end = line.offset + line.text.trim_end().len();
+            i += 1;
+            while let Some(line) = lines
+                .get(i)
+                .filter(|line| !line.is_blank() && line.indentation() == text_indentation)
+            {
+                end = line.offset + line.text.trim_end().len();
+                i += 1;
+            }
+
+            let paragraph = text.get(start..end).unwrap_or_default();
+            let Some(paragraph) = paragraph.strip_suffix("::") else {
+                push(start, paragraph, Kind::Text);
+                continue;
+            };
+
+            // The paragraph introduces a literal block, and its `::` is displayed as `:` if it follows some text
+            let paragraph = if paragraph.ends_with(char::is_whitespace) {
+                paragraph.trim_end()
+            } else {
+                text.get(start..end - 1).unwrap_or_default()
+            };
+            push(start, paragraph, Kind::Text);
+            i = block_end(i, text_indentation);
+        }
+
+        Ok(Self { lintables })
+    }
+}
+
+impl Parsed for ParsedRst {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        _source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables
+                .iter()
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, _source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        Box::new(self.lintables.iter().cloned())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for reStructuredText
+    pub fn rst() -> Self {
+        Self {
+            name: "rst",
+            detections: &["*.rst"],
+            parser: Mode::Custom(Box::new(move |text| Ok(Box::new(ParsedRst::new(text)?)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+    use crate::lang::{Kind, LintableString};
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "rst"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "rst",
+            Language::from_filename(OsStr::new("index.rst"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let rst = r"=====
+Guide
+=====
+
+Getting started
+---------------
+
+This is a paragraph with ``inline literal`` text
+and a role like :func:`main` on two lines.
+
+.. |version| replace:: 1.0
+.. note::
+
+   This directive is not checked.
+
+.. code-block:: python
+
+   print('not checked')
+
+Use it like this::
+
+    not checked
+
+- First item of the list
+- Second item with |version| inside
+
+>>> print('not checked')
+
+:param name: The name of the user
+";
+        let source = SharedSource::new("index.rst", rst.as_bytes().to_vec());
+        let mut parsed = Language::rst().parse(&source).unwrap();
+        let strings = parsed.strings(source.as_ref()).collect::<Vec<_>>();
+        let string = |value: &str| LintableString::new(rst.find(value).unwrap(), value);
+        assert_eq!(
+            strings,
+            [
+                string("Guide"),
+                string("Getting started"),
+                string("This is a paragraph with "),
+                string(" text\nand a role like "),
+                string(" on two lines."),
+                string("Use it like this:"),
+                string("First item of the list"),
+                string("Second item with "),
+                string(" inside"),
+                string("The name of the user"),
+            ]
+        );
+
+        let mut parsed = Language::rst().parse(&source).unwrap();
+        let headings = parsed
+            .lintables(source.as_ref())
+            .filter(|lintable| lintable.kind() == Kind::Heading)
+            .map(|lintable| lintable.as_str().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(headings, ["Guide", "Getting started"]);
+    }
+
+    #[test]
+    fn references() {
+        let rst = "See the `documentation <https://example.com>`_ and the footnote [1]_ here.\n\n\
+                   Expanded literal block ::\n\n    not checked\n";
+        let source = SharedSource::new("index.rst", rst.as_bytes().to_vec());
+        let mut parsed = Language::rst().parse(&source).unwrap();
+        let strings = parsed
+            .strings(source.as_ref())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            strings,
+            [
+                "See the ",
+                " and the footnote ",
+                " here.",
+                "Expanded literal block"
+            ]
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use tree_sitter::{Node, Parser, Tree};
+
+use crate::tree::PreorderTraversal;
+
+use super::{Kind, Language, Lintable, LintableNode, LintableString, Mode, Parsed, Replacement};
+
+/// State of a string unit whose value has been translated and reviewed
+const TRANSLATED: &str = "translated";
+
+/// Returns the content of a JSON string without its quotes
+fn string<'s>(node: Node<'_>, source: &'s [u8]) -> Option<&'s str> {
+    if node.kind() != "string" {
+        return None;
+    }
+
+    Some(node.utf8_text(source).ok()?.trim_matches('"'))
+}
+
+/// Parses the 4 hexadecimal digits of a `\uXXXX` escape sequence
+fn hex(escape: &str) -> Option<u32> {
+    u32::from_str_radix(escape.strip_prefix("\\u")?.get(..4)?, 16).ok()
+}
+
+/// Decodes a `\uXXXX` escape sequence, or a pair of them for a character outside of the BMP (e.g., an emoji),
+/// and returns it along with its length in the source
+fn decode_unicode(escape: &str) -> Option<(char, usize)> {
+    let high = hex(escape)?;
+    if let Some(c) = char::from_u32(high) {
+        return Some((c, 6));
+    }
+
+    let low = hex(escape.get(6..)?)?;
+    if !(0xD800..0xDC00).contains(&high) || !(0xDC00..0xE000).contains(&low) {
+        return None;
+    }
+    let c = char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))?;
+
+    Some((c, 12))
+}
+
+/// Builds a lintable string from a JSON string, with its escape sequences decoded (e.g., `\"` or `\u2019`)
+fn decoded(node: Node<'_>, source: &[u8]) -> Option<Lintable> {
+    let text = node.utf8_text(source).ok()?;
+    let content = text.strip_prefix('"')?.strip_suffix('"')?;
+    let offset = node.start_byte() + 1;
+
+    let mut value = String::with_capacity(content.len());
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while let Some(c) = content.get(i..).and_then(|rest| rest.chars().next()) {
+        if c != '\\' {
+            value.push(c);
+            i += c.len_utf8();
+            continue;
+        }
+
+        let escape = content.get(i..).unwrap_or_default();
+        let Some(escaped) = escape.chars().nth(1) else {
+            break;
+        };
+        let (decoded, source_len) = match escaped {
+            'u' => decode_unicode(escape).unwrap_or((escaped, 2)),
+            'n' => ('\n', 2),
+            't' => ('\t', 2),
+            'r' => ('\r', 2),
+            'b' => ('\u{8}', 2),
+            'f' => ('\u{C}', 2),
+            c => (c, 1 + c.len_utf8()),
+        };
+        replacements.push(Replacement {
+            index: value.len(),
+            len: decoded.len_utf8(),
+            source_len,
+        });
+        value.push(decoded);
+        i += source_len;
+    }
+
+    if value.trim().len() <= 3 {
+        return None;
+    }
+
+    Some(
+        Lintable::new(LintableString::new(offset, value), Kind::Text)
+            .with_replacements(replacements),
+    )
+}
+
+/// Returns the value of a key of a JSON object
+fn field<'t>(object: Node<'t>, key: &str, source: &[u8]) -> Option<Node<'t>> {
+    pairs(object, source)
+        .into_iter()
+        .find_map(|(name, value)| (name == key).then_some(value))
+}
+
+/// Returns the keys of a JSON object along with their values
+fn pairs<'t, 's>(object: Node<'t>, source: &'s [u8]) -> Vec<(&'s str, Node<'t>)> {
+    let mut cursor = object.walk();
+    object
+        .children(&mut cursor)
+        .filter(|node| node.kind() == "pair")
+        .filter_map(|pair| {
+            let key = string(pair.child_by_field_name("key")?, source)?;
+            Some((key, pair.child_by_field_name("value")?))
+        })
+        .collect()
+}
+
+/// Parser for Apple string catalogs (`.xcstrings`) that returns the values of the localizations
+///
+/// Each value is checked with the locale of its localization, and a value that has not been
+/// marked as translated (e.g., `needs_review`) is considered fuzzy.
+struct ParsedStringCatalog {
+    tree: Tree,
+}
+
+impl ParsedStringCatalog {
+    pub fn new(text: impl AsRef<[u8]>) -> anyhow::Result<Self> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter::Language::new(tree_sitter_json::LANGUAGE))?;
+        let Some(tree) = parser.parse(text.as_ref(), None) else {
+            anyhow::bail!("Invalid language");
+        };
+
+        Ok(Self { tree })
+    }
+}
+
+impl Parsed for ParsedStringCatalog {
+    fn lintable_nodes<'t>(&'t mut self) -> Box<dyn Iterator<Item = LintableNode<'t>> + 't> {
+        Box::new(std::iter::empty())
+    }
+
+    fn strings<'t>(
+        &'t mut self,
+        source: &'t [u8],
+    ) -> Box<dyn Iterator<Item = LintableString> + 't> {
+        Box::new(
+            self.lintables(source)
+                .map(|lintable| LintableString::new(lintable.offset(), lintable.as_str())),
+        )
+    }
+
+    fn lintables<'t>(&'t mut self, source: &'t [u8]) -> Box<dyn Iterator<Item = Lintable> + 't> {
+        let catalog = self.tree.root_node().named_child(0);
+        let strings = catalog.and_then(|catalog| field(catalog, "strings", source));
+        let localizations = strings
+            .map(|strings| pairs(strings, source))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(_, string)| field(string, "localizations", source))
+            .flat_map(|localizations| pairs(localizations, source));
+
+        let mut lintables = Vec::new();
+        for (locale, localization) in localizations {
+            // The string units can be nested in variations (e.g., plural forms)
+            let units = PreorderTraversal::from(localization.walk())
+                .filter(|node| node.kind() == "pair")
+                .filter(|pair| {
+                    pair.child_by_field_name("key")
+                        .and_then(|key| string(key, source))
+                        == Some("stringUnit")
+                })
+                .filter_map(|pair| pair.child_by_field_name("value"));
+            for unit in units {
+                let Some(value) = field(unit, "value", source) else {
+                    continue;
+                };
+                let state = field(unit, "state", source).and_then(|state| string(state, source));
+                let is_fuzzy = state.is_some_and(|state| state != TRANSLATED);
+
+                let Some(lintable) = decoded(value, source) else {
+                    continue;
+                };
+                let lintable = lintable.with_locale(Some(locale));
+                lintables.push(if is_fuzzy { lintable.fuzzy() } else { lintable });
+            }
+        }
+
+        Box::new(lintables.into_iter())
+    }
+}
+
+impl Language {
+    /// Creates a language parser for Apple string catalogs
+    pub fn xcstrings() -> Self {
+        Self {
+            name: "xcstrings",
+            detections: &["*.xcstrings"],
+            parser: Mode::Custom(Box::new(move |text| {
+                Ok(Box::new(ParsedStringCatalog::new(text)?))
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use crate::SharedSource;
+
+    use super::Language;
+
+    #[test]
+    fn exists_in_iter() {
+        assert!(Language::iter().any(|lang| lang.name() == "xcstrings"));
+    }
+
+    #[test]
+    fn find_from_filename() {
+        assert_eq!(
+            "xcstrings",
+            Language::from_filename(OsStr::new("Localizable.xcstrings"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn lintable_strings() {
+        let catalog = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "Save the file" : {
+      "localizations" : {
+        "de" : {
+          "stringUnit" : { "state" : "translated", "value" : "Datei \"speichern\" nicht vergessen" }
+        },
+        "fr" : {
+          "stringUnit" : { "state" : "needs_review", "value" : "Ne pas l\u2019oublier \ud83d\ude00" }
+        }
+      }
+    },
+    "%lld files" : {
+      "localizations" : {
+        "fr" : {
+          "variations" : {
+            "plural" : {
+              "one" : { "stringUnit" : { "state" : "translated", "value" : "%lld fichier" } },
+              "other" : { "stringUnit" : { "state" : "translated", "value" : "%lld fichiers" } }
+            }
+          }
+        }
+      }
+    },
+    "Untranslated" : {}
+  },
+  "version" : "1.0"
+}
+"#;
+        let source = SharedSource::new("Localizable.xcstrings", catalog.as_bytes().to_vec());
+        let mut parsed = Language::xcstrings().parse(&source).unwrap();
+        let lintables = parsed
+            .lintables(source.as_ref())
+            .map(|lintable| {
+                (
+                    lintable.offset(),
+                    lintable.as_str().to_owned(),
+                    lintable.locale().map(ToOwned::to_owned),
+                    lintable.is_fuzzy(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let lintable = |value: &str, start: &str, locale: &str, is_fuzzy: bool| {
+            (
+                catalog.find(start).unwrap(),
+                value.to_owned(),
+                Some(locale.to_owned()),
+                is_fuzzy,
+            )
+        };
+        assert_eq!(
+            lintables,
+            [
+                lintable("Datei \"speichern\" nicht vergessen", "Datei", "de", false),
+                lintable("Ne pas l’oublier 😀", "Ne pas", "fr", true),
+                lintable("%lld fichier", "%lld fichier\"", "fr", false),
+                lintable("%lld fichiers", "%lld fichiers", "fr", false),
+            ]
+        );
+
+        let apostrophe = parsed
+            .lintables(source.as_ref())
+            .find(|lintable| lintable.as_str().starts_with("Ne pas"))
+            .unwrap();
+        let index = apostrophe.as_str().find('’').unwrap();
+        assert_eq!(
+            apostrophe.source_span((index, '’'.len_utf8()).into()),
+            (catalog.find("\\u2019").unwrap(), 6).into()
+        );
+    }
+}
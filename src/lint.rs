@@ -1,19 +1,58 @@
+use std::collections::HashMap;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use miette::{SourceCode, SourceSpan};
 
+pub mod article;
+pub mod brackets;
+pub mod capitalization;
+pub mod dash;
+pub mod doubled;
+pub mod error_message;
+pub mod german_quotes;
+pub mod heading;
+pub mod inverted_marks;
+pub mod pairs;
 pub mod punctuation;
-
+pub mod symbols;
+pub mod unicode;
+pub mod unit;
+
+use self::article::Article;
+use self::brackets::Brackets;
+use self::capitalization::Capitalization;
+use self::dash::Dash;
+use self::doubled::Doubled;
+use self::error_message::ErrorMessage;
+use self::german_quotes::GermanQuotes;
+use self::heading::Heading;
+use self::inverted_marks::InvertedMarks;
+use self::pairs::Pairs;
 use self::punctuation::Punctuation;
+use self::symbols::Symbols;
+use self::unicode::Unicode;
+use self::unit::Unit;
 
 use crate::SharedSource;
-use crate::lang::{Language, LintableString, Parsed};
+use crate::config::EngineConfig;
+use crate::lang::{Encoding, Kind, Language, Lintable, Parsed};
 
 /// Type that represents a rule that checks for typos
 pub trait Rule {
     /// Returns the typos found by applying this rule to an array of bytes
     fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>>;
+
+    /// Returns the typos found by applying this rule to a string found in a source.
+    ///
+    /// By default, only text meant to be read by humans is checked, comments are ignored.
+    fn check_lintable(&self, lintable: &Lintable) -> Vec<Box<dyn Typo>> {
+        if lintable.kind() == Kind::Comment {
+            return Vec::new();
+        }
+
+        self.check(lintable.as_str().as_bytes())
+    }
 }
 
 /// The kind of action to perform to fix the lint suggestion
@@ -23,12 +62,19 @@ pub enum Fix {
 
     /// Removes some characters
     Remove { span: SourceSpan },
+
+    /// Replaces some characters with a new string
+    Replace {
+        span: SourceSpan,
+        replacement: String,
+    },
 }
 
 pub struct TypoFixer {
     path: PathBuf,
     buffer: Vec<u8>,
-    offset: isize,
+    /// Fixes applied so far with the position they were applied at and how they changed the length of the buffer
+    edits: Vec<(usize, isize)>,
 }
 
 impl TypoFixer {
@@ -39,14 +85,23 @@ impl TypoFixer {
         Ok(Self {
             path: path.into(),
             buffer,
-            offset: 0,
+            edits: Vec::new(),
         })
     }
 
+    /// Applies the fix of a typo.
+    ///
+    /// Typos can be given in any order, as long as their spans do not overlap.
     pub fn fix(&mut self, typo: &dyn Typo) -> anyhow::Result<()> {
-        self.offset += typo
-            .fix()
-            .apply_with_offset(&mut self.buffer, self.offset)?;
+        let position = typo.span().offset();
+        let offset = self
+            .edits
+            .iter()
+            .filter(|(edit_position, _)| *edit_position < position)
+            .map(|(_, delta)| delta)
+            .sum();
+        let delta = typo.fix().apply_with_offset(&mut self.buffer, offset)?;
+        self.edits.push((position, delta));
 
         Ok(())
     }
@@ -87,6 +142,17 @@ impl Fix {
 
                 Ok(-span.len().try_into()?)
             }
+            Self::Replace { span, replacement } => {
+                let typo_offset: isize = span.offset().try_into()?;
+                let start: usize = (offset + typo_offset).try_into()?;
+                let end = start + span.len();
+
+                buffer.splice(start..end, replacement.bytes());
+
+                let added: isize = replacement.len().try_into()?;
+                let removed: isize = span.len().try_into()?;
+                Ok(added - removed)
+            }
         }
     }
 }
@@ -96,8 +162,8 @@ pub trait Typo: miette::Diagnostic + std::error::Error + Sync + Send {
     /// Span that identify where the typo is located
     fn span(&self) -> SourceSpan;
 
-    /// Specify within which source the typo has been found
-    fn with_source(&mut self, src: SharedSource, offset: usize);
+    /// Specify within which source and string the typo has been found, to map its spans to the source
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable);
 
     /// Returns the action to perform to fix the typo
     fn fix(&self) -> Fix {
@@ -105,11 +171,85 @@ pub trait Typo: miette::Diagnostic + std::error::Error + Sync + Send {
     }
 }
 
+/// A typo whose fix is written in the syntax of its source (e.g., with escape sequences)
+#[derive(Debug)]
+struct EncodedTypo {
+    typo: Box<dyn Typo>,
+    encoding: Encoding,
+}
+
+impl std::fmt::Display for EncodedTypo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.typo.fmt(f)
+    }
+}
+
+impl std::error::Error for EncodedTypo {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.typo.source()
+    }
+}
+
+impl miette::Diagnostic for EncodedTypo {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.typo.code()
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        self.typo.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.typo.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.typo.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.typo.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        self.typo.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+        self.typo.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn miette::Diagnostic> {
+        self.typo.diagnostic_source()
+    }
+}
+
+impl Typo for EncodedTypo {
+    fn span(&self) -> SourceSpan {
+        self.typo.span()
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.typo.with_source(src, lintable);
+    }
+
+    fn fix(&self) -> Fix {
+        match self.typo.fix() {
+            Fix::Replace { span, replacement } => Fix::Replace {
+                span,
+                replacement: self.encoding.encode(&replacement),
+            },
+            fix => fix,
+        }
+    }
+}
+
 /// Detects typos in a file
 pub struct Linter {
     parsed: Box<dyn Parsed>,
     source: SharedSource,
     rules: Vec<Box<dyn Rule>>,
+    config: EngineConfig,
     ignore_re: Vec<regex::Regex>,
 }
 
@@ -117,8 +257,7 @@ impl Linter {
     /// Builds a linter that checks for typos in the file at the given path
     pub fn from_path(source: impl AsRef<Path>) -> anyhow::Result<Option<Self>> {
         let path = source.as_ref();
-        let filename = path.file_name().unwrap_or_default();
-        let Some(language) = Language::from_filename(filename) else {
+        let Some(language) = Language::from_path(path) else {
             // TODO: parse the file as a text file without tree-sitter
             return Ok(None);
         };
@@ -138,16 +277,24 @@ impl Linter {
         let source = SharedSource::new(source_name, source_content);
         let parsed = lang.parse(&source)?;
 
-        let rules = vec![Box::new(Punctuation) as Box<dyn Rule>];
+        let config = EngineConfig::default();
+        let rules = rules(&config);
 
         Ok(Self {
             parsed,
             source,
             rules,
+            config,
             ignore_re: Vec::new(),
         })
     }
 
+    /// Configures the rules applied by the linter
+    pub fn set_config(&mut self, config: &EngineConfig) {
+        self.rules = rules(config);
+        self.config = config.clone();
+    }
+
     /// Extends the list of regexes that prevents some strings from being checked
     pub fn extend_ignore_re(&mut self, ignore_re: &[regex::Regex]) {
         self.ignore_re.extend_from_slice(ignore_re);
@@ -179,22 +326,98 @@ impl Linter {
     }
 }
 
+/// Returns the character that precedes the given byte index
+fn char_before(text: &str, index: usize) -> Option<char> {
+    text.get(..index)?.chars().next_back()
+}
+
+/// Returns the character that starts at the given byte index
+fn char_after(text: &str, index: usize) -> Option<char> {
+    text.get(index..)?.chars().next()
+}
+
+/// Whether the text looks like it has been written for humans rather than being code
+fn looks_like_prose(text: &str) -> bool {
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphabetic()))
+        .filter(|word| word.chars().count() >= 2 && word.chars().all(char::is_alphabetic))
+        .take(2)
+        .count()
+        == 2
+}
+
+/// Builds the list of rules to apply based on the config
+fn rules(config: &EngineConfig) -> Vec<Box<dyn Rule>> {
+    let language = config.language();
+    let is_english = language.as_deref().is_none_or(|language| language == "en");
+
+    let mut rules: Vec<Box<dyn Rule>> = vec![
+        Box::new(Punctuation::new(language.as_deref())),
+        Box::new(Dash::new(config.em_dash)),
+        Box::new(Pairs::new(language.as_deref())),
+        Box::new(Unicode),
+        Box::new(Capitalization::new(&config.extend_abbreviations)),
+        Box::new(ErrorMessage::new(
+            config.error_message_case,
+            config.error_message_period,
+        )),
+        Box::new(Doubled::new(config.max_repeated_marks)),
+        Box::new(Brackets),
+        Box::new(Unit::new(
+            config.unit_convention,
+            config.unit_space,
+            language.as_deref(),
+        )),
+        Box::new(Heading::new(config.heading_case)),
+    ];
+    if is_english {
+        rules.push(Box::new(Article::new(
+            &config.extend_a_words,
+            &config.extend_an_words,
+        )));
+    }
+    if config.pedantic() {
+        rules.push(Box::new(Symbols));
+    }
+    match language.as_deref() {
+        Some("de") => rules.push(Box::new(GermanQuotes::new(config.german_quotes))),
+        Some("es") => rules.push(Box::new(InvertedMarks)),
+        _ => {}
+    }
+
+    rules
+}
+
 /// Iterator over the typos found in a file
 pub struct Iter<'t> {
-    strings: Box<dyn Iterator<Item = LintableString> + 't>,
+    lintables: Box<dyn Iterator<Item = Lintable> + 't>,
     source: SharedSource,
     typos: Vec<Box<dyn Typo>>,
     rules: &'t [Box<dyn Rule>],
+    config: &'t EngineConfig,
+    /// Rules of the locales found in the source that differ from the configured one
+    localized_rules: HashMap<String, Vec<Box<dyn Rule>>>,
     ignore_re: &'t [regex::Regex],
 }
 
 impl<'t> Iter<'t> {
     fn new(linter: &'t mut Linter) -> Self {
+        let lintables = linter.parsed.lintables(linter.source.as_ref());
+        let lintables: Box<dyn Iterator<Item = Lintable> + 't> = match linter.config.message_format
+        {
+            Some(format) => {
+                Box::new(lintables.flat_map(move |lintable| lintable.split_message(format)))
+            }
+            None => lintables,
+        };
+
         Self {
-            strings: linter.parsed.strings(linter.source.as_ref()),
+            lintables,
             source: linter.source.clone(),
             typos: vec![],
             rules: &linter.rules,
+            config: &linter.config,
+            localized_rules: HashMap::new(),
             ignore_re: &linter.ignore_re,
         }
     }
@@ -209,23 +432,40 @@ impl Iterator for Iter<'_> {
                 return Some(typo);
             }
 
-            let string = self.strings.next()?;
+            let lintable = self.lintables.next()?;
 
-            let offset = string.offset();
-            let ignored = self.ignore_re.iter().any(|re| re.is_match(string.as_str()));
-            if ignored {
+            let ignored = self
+                .ignore_re
+                .iter()
+                .any(|re| re.is_match(lintable.as_str()));
+            if ignored || (lintable.is_fuzzy() && self.config.skip_fuzzy()) {
                 continue;
             }
 
-            let source = self.source.clone();
-            let typos = self
-                .rules
+            let config = self.config;
+            let rules = match lintable.locale() {
+                Some(locale) if config.locale.as_deref() != Some(locale) => self
+                    .localized_rules
+                    .entry(locale.to_owned())
+                    .or_insert_with(|| {
+                        rules(&EngineConfig {
+                            locale: Some(locale.to_owned()),
+                            ..config.clone()
+                        })
+                    }),
+                _ => self.rules,
+            };
+            let typos = rules
                 .iter()
-                .flat_map(move |rule| rule.check(string.as_str().as_bytes()))
-                .map(move |mut typo| {
-                    typo.with_source(source.clone(), offset);
-                    typo
-                });
+                .flat_map(|rule| rule.check_lintable(&lintable))
+                .map(|mut typo| {
+                    typo.with_source(self.source.clone(), &lintable);
+                    match lintable.encoding() {
+                        Some(encoding) => Box::new(EncodedTypo { typo, encoding }),
+                        None => typo,
+                    }
+                })
+                .collect::<Vec<_>>();
 
             self.typos.extend(typos);
         }
@@ -428,6 +668,459 @@ mod tests {
         assert_eq!(typos, 0);
     }
 
+    #[cfg(feature = "lang-rust")]
+    #[test]
+    fn typo_rust_error_message() {
+        use crate::config::{EngineConfig, ErrorMessageCase};
+
+        let rust = r#"
+        fn func() -> anyhow::Result<()> {
+            println!("Hello world.");
+            if false {
+                return Err(format!("Invalid value: {value}").into());
+            }
+            anyhow::bail!("failed to do something.");
+        }
+        "#;
+        let mut linter = Linter::new(&Language::rust(), rust, "file.rs").unwrap();
+        linter.set_config(&EngineConfig {
+            error_message_case: Some(ErrorMessageCase::Lowercase),
+            error_message_period: Some(false),
+            ..Default::default()
+        });
+
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        typos.sort_by_key(|typo| typo.span().offset());
+        assert_eq!(
+            typos.iter().map(|typo| typo.span()).collect::<Vec<_>>(),
+            [(140, 1).into(), (237, 1).into()]
+        );
+    }
+
+    #[cfg(feature = "lang-go")]
+    #[test]
+    fn typo_go_error_message() {
+        use crate::config::{EngineConfig, ErrorMessageCase};
+
+        let go = r#"
+        func f() error {
+            fmt.Println("Hello world.")
+            return fmt.Errorf("Failed to do something: %w", errors.New("invalid value."))
+        }
+        "#;
+        let mut linter = Linter::new(&Language::go(), go, "file.go").unwrap();
+        linter.set_config(&EngineConfig {
+            error_message_case: Some(ErrorMessageCase::Lowercase),
+            error_message_period: Some(false),
+            ..Default::default()
+        });
+
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        typos.sort_by_key(|typo| typo.span().offset());
+        assert_eq!(
+            typos.iter().map(|typo| typo.span()).collect::<Vec<_>>(),
+            [(97, 1).into(), (151, 1).into()]
+        );
+    }
+
+    #[cfg(feature = "lang-java")]
+    #[test]
+    fn typo_java_error_message() {
+        use crate::config::{EngineConfig, ErrorMessageCase};
+
+        let java = r#"
+        void f() {
+            System.out.println("Hello world.");
+            throw new IllegalArgumentException("Invalid value.");
+        }
+        "#;
+        let mut linter = Linter::new(&Language::java(), java, "File.java").unwrap();
+        linter.set_config(&EngineConfig {
+            error_message_case: Some(ErrorMessageCase::Lowercase),
+            error_message_period: Some(false),
+            ..Default::default()
+        });
+
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        typos.sort_by_key(|typo| typo.span().offset());
+        assert_eq!(
+            typos.iter().map(|typo| typo.span()).collect::<Vec<_>>(),
+            [
+                (java.find("Invalid").unwrap(), 1).into(),
+                (java.rfind(".\");").unwrap(), 1).into()
+            ]
+        );
+    }
+
+    #[cfg(feature = "lang-java")]
+    #[test]
+    fn typo_properties_latin1_apply_fixes() {
+        use crate::lint::TypoFixer;
+
+        let properties = b"greeting=Caf\xe9 ouvert de 10-20 heures\n";
+        let properties_fixed = b"greeting=Caf\xe9 ouvert de 10\\u201320 heures\n";
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("messages.properties");
+        std::fs::write(&file_path, properties).unwrap();
+
+        let mut linter = Linter::from_path(&file_path).unwrap().unwrap();
+
+        let typos = linter.iter().collect::<Vec<_>>();
+        assert_eq!(typos.len(), 1);
+
+        let mut fixer = TypoFixer::new(&file_path).unwrap();
+        for typo in typos {
+            fixer.fix(typo.as_ref()).unwrap();
+        }
+
+        drop(fixer);
+
+        assert_eq!(
+            properties_fixed.as_slice(),
+            std::fs::read(file_path).unwrap()
+        );
+    }
+
+    #[cfg(feature = "lang-python")]
+    #[test]
+    fn typo_python_error_message() {
+        use crate::config::{EngineConfig, ErrorMessageCase};
+
+        let python = r#"
+def f():
+    print("hello world")
+    raise ValueError("invalid value")
+"#;
+        let mut linter = Linter::new(&Language::python(), python, "file.py").unwrap();
+        linter.set_config(&EngineConfig {
+            error_message_case: Some(ErrorMessageCase::Uppercase),
+            error_message_period: Some(true),
+            ..Default::default()
+        });
+
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        typos.sort_by_key(|typo| typo.span().offset());
+        assert_eq!(
+            typos.iter().map(|typo| typo.span()).collect::<Vec<_>>(),
+            [(57, 1).into(), (70, 0).into()]
+        );
+    }
+
+    #[cfg(feature = "lang-markdown")]
+    #[test]
+    fn typo_markdown_spanish() {
+        use miette::Diagnostic;
+
+        use crate::config::EngineConfig;
+
+        let markdown = "Qué pasa? Voy a ir ahora.";
+        let mut linter = Linter::new(&Language::markdown(), markdown, "file.md").unwrap();
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(
+            typo.code().unwrap().to_string(),
+            "typope::indefinite-article"
+        );
+        assert!(typos.is_empty());
+
+        linter.set_config(&EngineConfig {
+            locale: Some("es".into()),
+            ..Default::default()
+        });
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(
+            typo.code().unwrap().to_string(),
+            "typope::missing-inverted-mark"
+        );
+        assert!(typos.is_empty());
+    }
+
+    #[cfg(feature = "lang-markdown")]
+    #[test]
+    fn typo_markdown_heading() {
+        use miette::Diagnostic;
+
+        use crate::config::{EngineConfig, HeadingCase};
+
+        let markdown = "# Supported Languages:\n\nSome Text Here:\n";
+        let mut linter = Linter::new(&Language::markdown(), markdown, "file.md").unwrap();
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(
+            typo.code().unwrap().to_string(),
+            "typope::heading-punctuation"
+        );
+        assert_eq!(typo.span(), (21, 1).into());
+        assert!(typos.is_empty());
+
+        linter.set_config(&EngineConfig {
+            heading_case: Some(HeadingCase::Sentence),
+            ..Default::default()
+        });
+        let mut typos = linter
+            .iter()
+            .map(|typo| (typo.code().unwrap().to_string(), typo.span().offset()))
+            .collect::<Vec<_>>();
+        typos.sort_by_key(|(_, offset)| *offset);
+        assert_eq!(
+            typos,
+            [
+                ("typope::heading-case".into(), 12),
+                ("typope::heading-punctuation".into(), 21)
+            ]
+        );
+    }
+
+    #[cfg(feature = "lang-po")]
+    #[test]
+    fn typo_po_locale() {
+        use miette::Diagnostic;
+
+        use crate::config::EngineConfig;
+
+        let po = "msgid \"\"\nmsgstr \"Language: de\\n\"\n\n\
+                  msgid \"Click on “Save”.\"\nmsgstr \"Klicken Sie auf “Speichern”.\"\n\n\
+                  #, fuzzy\nmsgid \"Click on “Open”.\"\nmsgstr \"Klicken Sie auf “Öffnen”.\"\n";
+        let mut linter = Linter::new(&Language::po(), po, "file.po").unwrap();
+        let typos = linter
+            .iter()
+            .map(|typo| (typo.code().unwrap().to_string(), typo.span().offset()))
+            .collect::<Vec<_>>();
+        assert_eq!(typos.len(), 4);
+        assert!(
+            typos
+                .iter()
+                .all(|(code, _)| code == "typope::german-quotation-mark")
+        );
+        assert!(
+            typos
+                .iter()
+                .all(|(_, offset)| *offset > po.find("Klicken").unwrap())
+        );
+
+        linter.set_config(&EngineConfig {
+            skip_fuzzy: Some(true),
+            ..Default::default()
+        });
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (po.find("“Speichern").unwrap(), 3).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (po.find("”.\"\n\n").unwrap(), 3).into());
+        assert!(typos.is_empty());
+    }
+
+    #[cfg(feature = "lang-po")]
+    #[test]
+    fn typo_po_french_non_breaking_space() {
+        let po = "msgid \"\"\nmsgstr \"Language: fr\\n\"\n\n\
+                  msgid \"Hello!\"\nmsgstr \"Bonjour\u{A0}!\"\n\n\
+                  msgid \"Hello, world!\"\nmsgstr \"Bonjour , le monde\u{202F}!\"\n";
+        let mut linter = Linter::new(&Language::po(), po, "file.po").unwrap();
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (po.find(" , ").unwrap(), 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[cfg(feature = "lang-po")]
+    #[test]
+    fn typo_po_unit_convention() {
+        let po = |language: &str| {
+            format!(
+                "msgid \"\"\nmsgstr \"Language: {language}\\n\"\n\n\
+                 msgid \"It uses 5% of the CPU.\"\nmsgstr \"Es nutzt 5 % der CPU.\"\n"
+            )
+        };
+        let count = |po: String| {
+            Linter::new(&Language::po(), po, "file.po")
+                .unwrap()
+                .iter()
+                .count()
+        };
+        assert_eq!(count(po("de")), 0);
+        assert_eq!(count(po("fr")), 0);
+        assert_eq!(count(po("en")), 1);
+    }
+
+    #[cfg(feature = "lang-json")]
+    #[test]
+    fn typo_icu_message_format() {
+        use crate::config::{EngineConfig, MessageFormat};
+
+        let json = r#"{"files": "{count, plural , one {# file !} other {# files}} found"}"#;
+        let mut linter = Linter::new(&Language::json(), json, "en.json").unwrap();
+        assert_eq!(linter.iter().count(), 2);
+
+        linter.set_config(&EngineConfig {
+            message_format: Some(MessageFormat::Icu),
+            ..Default::default()
+        });
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (json.find(" !").unwrap(), 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[cfg(feature = "lang-apple")]
+    #[test]
+    fn typo_xcstrings_locale() {
+        use miette::Diagnostic;
+
+        let catalog = r#"{
+  "strings" : {
+    "Click on “Save”." : {
+      "localizations" : {
+        "de" : { "stringUnit" : { "state" : "translated", "value" : "Klicken Sie auf “Speichern”." } },
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Click on “Save”." } },
+        "fr" : { "stringUnit" : { "state" : "translated", "value" : "Cliquez sur “Enregistrer”." } }
+      }
+    }
+  }
+}"#;
+        let mut linter =
+            Linter::new(&Language::xcstrings(), catalog, "Localizable.xcstrings").unwrap();
+        let mut typos = linter
+            .iter()
+            .map(|typo| (typo.code().unwrap().to_string(), typo.span()))
+            .collect::<Vec<_>>();
+        typos.sort_by_key(|(_, span)| span.offset());
+        let quote = |quote: &str| {
+            (
+                "typope::german-quotation-mark".to_owned(),
+                (catalog.find(quote).unwrap(), 3).into(),
+            )
+        };
+        assert_eq!(
+            typos,
+            [quote("“Speichern"), quote("”.\" } },\n        \"en")]
+        );
+    }
+
+    #[cfg(feature = "lang-latex")]
+    #[test]
+    fn typo_latex_spaces_and_dashes() {
+        use miette::Diagnostic;
+
+        let latex = r"\begin{document}
+Hello~! See pages 1-5 and 10--20 of \cite{book} --- with $x - y$.
+\end{document}
+";
+        let mut linter = Linter::new(&Language::latex(), latex, "file.tex").unwrap();
+        let mut typos = linter
+            .iter()
+            .map(|typo| (typo.code().unwrap().to_string(), typo.span()))
+            .collect::<Vec<_>>();
+        typos.sort_by_key(|(_, span)| span.offset());
+        assert_eq!(
+            typos,
+            [
+                (
+                    "typope::space-before-punctuation-mark".into(),
+                    (latex.find('~').unwrap(), 1).into()
+                ),
+                (
+                    "typope::hyphen-in-number-range".into(),
+                    (latex.find("-5").unwrap(), 1).into()
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "lang-latex")]
+    #[test]
+    fn typo_latex_apply_fixes() {
+        use crate::lint::TypoFixer;
+
+        let latex =
+            "\\begin{document}\nSee pages 10-20, it works - most of the time.\n\\end{document}\n";
+        let latex_fixed = "\\begin{document}\nSee pages 10--20, it works --- most of the time.\n\\end{document}\n";
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("file.tex");
+        std::fs::write(&file_path, latex).unwrap();
+
+        let mut linter = Linter::from_path(&file_path).unwrap().unwrap();
+
+        let typos = linter.iter().collect::<Vec<_>>();
+        assert_eq!(typos.len(), 2);
+
+        let mut fixer = TypoFixer::new(&file_path).unwrap();
+        for typo in typos {
+            fixer.fix(typo.as_ref()).unwrap();
+        }
+
+        drop(fixer);
+
+        assert_eq!(latex_fixed, std::fs::read_to_string(file_path).unwrap());
+    }
+
+    #[cfg(feature = "lang-html")]
+    #[test]
+    fn typo_html_entity() {
+        use miette::Diagnostic;
+
+        let html = "<p>It uses 5&nbsp;% of the memory&nbsp;!</p>";
+        let mut linter = Linter::new(&Language::html(), html, "file.html").unwrap();
+        let mut typos = linter
+            .iter()
+            .map(|typo| (typo.code().unwrap().to_string(), typo.span()))
+            .collect::<Vec<_>>();
+        typos.sort_by_key(|(_, span)| span.offset());
+        assert_eq!(
+            typos,
+            [
+                ("typope::unit-spacing".into(), (12, 6).into()),
+                (
+                    "typope::space-before-punctuation-mark".into(),
+                    (33, 6).into()
+                )
+            ]
+        );
+    }
+
+    #[cfg(feature = "lang-rust")]
+    #[test]
+    fn typo_rust_comment() {
+        let rust = "
+        // Only some rules apply to comments : like this one\u{200B}
+        fn func() {}
+        ";
+        let mut linter = Linter::new(&Language::rust(), rust, "file.rs").unwrap();
+
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        assert_eq!(typos.len(), 1);
+        let typo = typos.pop().unwrap();
+        assert_eq!(
+            format!("{}", typo.code().unwrap()),
+            "typope::invisible-character"
+        );
+        assert_eq!(typo.span(), (61, 3).into());
+    }
+
+    #[cfg(feature = "lang-markdown")]
+    #[test]
+    fn typo_markdown_heading_code_span() {
+        use miette::Diagnostic;
+
+        use crate::config::{EngineConfig, HeadingCase};
+
+        let markdown = "# Hello. `foo` with bar\n\n## Usage of `foo`.\n";
+        let mut linter = Linter::new(&Language::markdown(), markdown, "file.md").unwrap();
+        linter.set_config(&EngineConfig {
+            heading_case: Some(HeadingCase::Sentence),
+            ..Default::default()
+        });
+        let mut typos = linter.iter().collect::<Vec<_>>();
+        let typo = typos.pop().unwrap();
+        assert_eq!(
+            typo.code().unwrap().to_string(),
+            "typope::heading-punctuation"
+        );
+        assert_eq!(typo.span(), (markdown.rfind('.').unwrap(), 1).into());
+        assert!(typos.is_empty());
+    }
+
     #[cfg(feature = "lang-markdown")]
     #[test]
     fn typo_markdown_inline() {
@@ -462,13 +1155,52 @@ Hello mate `this should not trigger the rule : foobar` abc
         assert_eq!("145", String::from_utf8_lossy(&content));
     }
 
+    #[test]
+    fn apply_with_offset_replace() {
+        let mut content = b"1-2".to_vec();
+
+        let fix = Fix::Replace {
+            span: (1, 1).into(),
+            replacement: "–".into(),
+        };
+        let offset = fix.apply_with_offset(&mut content, 0).unwrap();
+        assert_eq!(offset, 2);
+        assert_eq!("1–2", String::from_utf8_lossy(&content));
+    }
+
+    #[cfg(feature = "lang-markdown")]
+    #[test]
+    fn typo_markdown_apply_fixes_in_any_order() {
+        use crate::lint::TypoFixer;
+
+        let markdown = r"Pages 1-5 : it works - most of the time !";
+        let markdown_fixed = r"Pages 1–5: it works — most of the time!";
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("file.md");
+        std::fs::write(&file_path, markdown.as_bytes()).unwrap();
+
+        let mut linter = Linter::from_path(&file_path).unwrap().unwrap();
+
+        let typos = linter.iter().collect::<Vec<_>>();
+        assert_eq!(typos.len(), 4);
+
+        let mut fixer = TypoFixer::new(&file_path).unwrap();
+        for typo in typos {
+            fixer.fix(typo.as_ref()).unwrap();
+        }
+
+        drop(fixer);
+
+        assert_eq!(markdown_fixed, std::fs::read_to_string(file_path).unwrap());
+    }
+
     #[cfg(feature = "lang-markdown")]
     #[test]
     fn typo_markdown_apply_multiple_fixes() {
         use crate::lint::TypoFixer;
 
         let markdown = r#"This should trigger the rule : foobar This one ! Annnnnd here ??? And !!! what about ! or this! and this ?? "another test with ! in it, but nothing else""#;
-        let markdown_fixed = r#"This should trigger the rule: foobar This one! Annnnnd here??? And!!! what about! or this! and this?? "another test with! in it, but nothing else""#;
+        let markdown_fixed = r#"This should trigger the rule: foobar This one! Annnnnd here??? And!!! what about! or this! And this?? "another test with! in it, but nothing else""#;
         let dir = tempfile::tempdir().unwrap();
         let file_path = dir.path().join("file.md");
         std::fs::write(&file_path, markdown.as_bytes()).unwrap();
@@ -476,7 +1208,7 @@ Hello mate `this should not trigger the rule : foobar` abc
         let mut linter = Linter::from_path(&file_path).unwrap().unwrap();
 
         let typos = linter.iter().collect::<Vec<_>>();
-        assert_eq!(typos.len(), 7);
+        assert_eq!(typos.len(), 8);
 
         let mut fixer = TypoFixer::new(&file_path).unwrap();
         for typo in typos.into_iter().rev() {
@@ -0,0 +1,379 @@
+//! Typographical mistakes related to the indefinite articles `a` and `an`.
+//!
+//! Here is a list of typos it can find:
+//! - [An indefinite article that does not agree with the following word](`TypoIndefiniteArticle`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo};
+
+/// Beginnings of words starting with a vowel letter that are pronounced with a consonant sound
+const A_WORDS: &[&str] = &[
+    "eu", "ewe", "ubiq", "ufo", "uk", "unanim", "unary", "unicode", "unicorn", "uniform", "unif",
+    "union", "unique", "unit", "univers", "unix", "ura", "uri", "uro", "usa", "use", "usu", "uten",
+    "uti", "uto",
+];
+
+/// Whole words starting with a vowel letter that are pronounced with a consonant sound,
+/// which cannot be matched as beginnings of words (e.g., `one` but not `onerous`)
+const A_WHOLE_WORDS: &[&str] = &["once", "one", "ones"];
+
+/// Beginnings of words starting with a consonant letter that are pronounced with a vowel sound
+const AN_WORDS: &[&str] = &["heir", "honest", "honor", "honour", "hour"];
+
+/// Words that cannot follow an article, so the article is most likely something else (e.g., a variable)
+const NOT_NOUNS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "if", "in", "is", "it", "of", "on", "or",
+    "the", "to", "was",
+];
+
+/// Letters whose name starts with a vowel sound when spelling an initialism (e.g., `an SQL query`)
+const AN_LETTERS: &[char] = &['A', 'E', 'F', 'H', 'I', 'L', 'M', 'N', 'O', 'R', 'S', 'X'];
+
+/// Acronyms pronounced as words rather than spelled out, whose first sound is a consonant
+/// even though the name of their first letter starts with a vowel sound (e.g., `a NASA mission`)
+const A_ACRONYMS: &[&str] = &[
+    "FIFO", "LAMP", "LAN", "LIFO", "LINQ", "MAC", "MIME", "NAND", "NAS", "NASA", "NAT", "NATO",
+    "NIC", "RAID", "RAM", "REST", "RISC", "ROM", "SAML", "SCSI", "SIM", "SOAP", "SWAT",
+];
+
+/// The indefinite article does not agree with the sound of the following word.
+///
+/// In English, `an` is used before a vowel sound, and `a` before a consonant sound.
+/// It depends on how the word is pronounced, not on how it is written.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `a error`, should be `an error`
+/// - `an user`, should be `a user`
+/// - `a hour`, should be `an hour`
+/// - `a HTML page`, should be `an HTML page`
+#[derive(Error, Debug, Diagnostic)]
+#[error("The indefinite article does not agree with the following word")]
+#[diagnostic(code("typope::indefinite-article"), url(docsrs))]
+pub struct TypoIndefiniteArticle {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Article here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoIndefiniteArticle {
+    fn new(span: impl Into<SourceSpan>, article: &str, replacement: String) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("use `{replacement}` instead of `{article}`"),
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoIndefiniteArticle {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// A rule that detects indefinite articles (`a` or `an`) that do not agree with the following word.
+///
+/// Currently, it can only find and generate the following typo: [`TypoIndefiniteArticle`].
+///
+/// It relies on a bundled list of exceptions for words whose pronunciation does not match
+/// their first letter (e.g., `an hour` or `a unicorn`). Initialisms are spelled out by default
+/// (e.g., `an SQL query`), except for a bundled list of acronyms pronounced as words (e.g., `a NASA mission`).
+/// It can be changed for some words with the configuration (e.g., `extend-a-words = ["SQL"]` to accept `a SQL query`).
+pub struct Article {
+    a_words: Vec<String>,
+    an_words: Vec<String>,
+}
+
+impl Article {
+    /// Creates the rule with additional words that must be preceded by `a` or `an`
+    pub fn new(a_words: &[String], an_words: &[String]) -> Self {
+        Self {
+            a_words: a_words.iter().map(|word| word.to_lowercase()).collect(),
+            an_words: an_words.iter().map(|word| word.to_lowercase()).collect(),
+        }
+    }
+
+    /// Whether the word must be preceded by `an` rather than `a`.
+    ///
+    /// Returns `None` when it cannot be decided (e.g., it is not a word).
+    fn expects_an(&self, word: &str) -> Option<bool> {
+        let lowercase = word.to_lowercase();
+        if self.a_words.contains(&lowercase) {
+            return Some(false);
+        }
+        if self.an_words.contains(&lowercase) {
+            return Some(true);
+        }
+
+        let first = word.chars().next()?;
+        if first.is_ascii_digit() {
+            return Some(Self::number_expects_an(word));
+        }
+        if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        let is_initialism = word.len() >= 2 && word.chars().all(|c| c.is_ascii_uppercase());
+        if is_initialism {
+            return Some(!A_ACRONYMS.contains(&word) && AN_LETTERS.contains(&first));
+        }
+
+        if A_WHOLE_WORDS.contains(&lowercase.as_str())
+            || A_WORDS.iter().any(|prefix| lowercase.starts_with(prefix))
+        {
+            return Some(false);
+        }
+        if AN_WORDS.iter().any(|prefix| lowercase.starts_with(prefix)) {
+            return Some(true);
+        }
+
+        Some(matches!(
+            first.to_ascii_lowercase(),
+            'a' | 'e' | 'i' | 'o' | 'u'
+        ))
+    }
+
+    /// Whether the number at the start of the word is spoken with a vowel sound
+    /// (e.g., `8`, `11th`, `18`, `80`, `800`, `11000`, or `1800s` read as `eighteen hundreds`)
+    fn number_expects_an(word: &str) -> bool {
+        let digits = word
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+            .unwrap_or_default();
+        // The number is spoken from its first group of thousands, except for
+        // numbers of 4 digits that are usually read in pairs (e.g., `1800`)
+        let leading = match digits.len() {
+            4 => 2,
+            len => (len - 1) % 3 + 1,
+        };
+        let group = digits.get(..leading).unwrap_or_default();
+
+        group.starts_with('8') || group == "11" || group == "18"
+    }
+}
+
+/// Returns the words separated by whitespace with their offset
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_inclusive(char::is_whitespace)
+        .scan(0, |offset, word| {
+            let start = *offset;
+            *offset += word.len();
+
+            Some((start, word.trim_end()))
+        })
+        .filter(|(_, word)| !word.is_empty())
+}
+
+impl Rule for Article {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+
+        let words = words(text).collect::<Vec<_>>();
+        words
+            .windows(2)
+            .filter_map(|pair| {
+                let [(offset, article), (_, next)] = pair else {
+                    return None;
+                };
+                let article_start = article.trim_start_matches(['(', '"', '“']);
+                let offset = offset + article.len() - article_start.len();
+                let article = article_start;
+                let is_an = match article {
+                    "a" | "A" => false,
+                    "an" | "An" | "AN" => true,
+                    _ => return None,
+                };
+
+                let next = next.trim_end_matches(|c: char| ",.;:!?)\"”'’".contains(c));
+                let word = next.split('-').next()?;
+                if NOT_NOUNS.contains(&word.to_lowercase().as_str()) {
+                    return None;
+                }
+                if !next.chars().all(|c| c.is_alphanumeric() || c == '-') {
+                    return None;
+                }
+                let expects_an = self.expects_an(word)?;
+                if expects_an == is_an {
+                    return None;
+                }
+
+                let replacement = match article {
+                    "a" => "an",
+                    "A" => "An",
+                    "AN" => "A",
+                    _ => article.get(..1)?,
+                };
+
+                Some(Box::new(TypoIndefiniteArticle::new(
+                    (offset, article.len()),
+                    article,
+                    replacement.into(),
+                )) as Box<dyn Typo>)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::{Fix, Rule};
+
+    use super::Article;
+
+    fn replacement(fix: Fix) -> Option<String> {
+        let Fix::Replace { replacement, .. } = fix else {
+            return None;
+        };
+
+        Some(replacement)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Article::new(&[], &[]).check(br"").is_empty());
+    }
+
+    #[test]
+    fn correct() {
+        let article = Article::new(&[], &[]);
+        assert!(
+            article
+                .check(br"an error for a user, an hour with a unicorn and an umbrella")
+                .is_empty()
+        );
+        assert!(
+            article
+                .check(
+                    br"an SQL query, a URL, an HTML page, a UUID, an 8-bit value, a one-time fix"
+                )
+                .is_empty()
+        );
+        assert!(
+            article
+                .check(br"an uninitialized value, a European country, an iPhone")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn a_instead_of_an() {
+        let mut typos = Article::new(&[], &[]).check(br"A error occurred after a hour");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (23, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "an");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (0, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "An");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn an_instead_of_a() {
+        let mut typos = Article::new(&[], &[]).check(br"create an user (an new one)");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (16, 2).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "a");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 2).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn whole_words() {
+        let article = Article::new(&[], &[]);
+        assert!(
+            article
+                .check(br"an onerous task, a one-time fix")
+                .is_empty()
+        );
+        assert!(article.check(br"a one, a once-only task").is_empty());
+        let mut typos = article.check(br"a onerous task");
+        let typo = typos.pop().unwrap();
+        assert_eq!(replacement(typo.fix()).unwrap(), "an");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn numbers() {
+        let article = Article::new(&[], &[]);
+        assert!(
+            article
+                .check(br"an 8, an 11th hour, an 18-year-old, an 80 percent, an 800 value")
+                .is_empty()
+        );
+        assert!(
+            article
+                .check(br"an 11000 limit, an 85th item, an 1800s house, an 8000 limit")
+                .is_empty()
+        );
+        assert!(
+            article
+                .check(br"a 1st item, a 100 value, a 118 value, a 180000 limit, a 110 value")
+                .is_empty()
+        );
+        assert_eq!(article.check(br"a 11th hour").len(), 1);
+        assert_eq!(article.check(br"an 12th hour").len(), 1);
+    }
+
+    #[test]
+    fn configured_words() {
+        let sql = ["SQL".to_owned()];
+        assert_eq!(Article::new(&[], &[]).check(br"a SQL query").len(), 1);
+        assert!(Article::new(&sql, &[]).check(br"a SQL query").is_empty());
+        assert_eq!(Article::new(&sql, &[]).check(br"an SQL query").len(), 1);
+
+        let herb = ["herb".to_owned()];
+        assert!(Article::new(&[], &herb).check(br"an herb").is_empty());
+    }
+
+    #[test]
+    fn acronyms() {
+        let article = Article::new(&[], &[]);
+        assert!(
+            article
+                .check(br"a NASA mission, a LAN, a RAID array, a SIM card, a REST API")
+                .is_empty()
+        );
+        assert_eq!(article.check(br"an NASA mission").len(), 1);
+        assert_eq!(article.check(br"a HTML page").len(), 1);
+    }
+
+    #[test]
+    fn not_articles() {
+        let article = Article::new(&[], &[]);
+        assert!(article.check(br"if a or b is set").is_empty());
+        assert!(article.check(br"let a = 1").is_empty());
+        assert!(article.check(br"pass -a error").is_empty());
+        assert!(article.check(br"a some_identifier").is_empty());
+        assert!(article.check(br"a `code`").is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+//! Typographical mistakes related to the spacing inside parentheses and brackets.
+//!
+//! Here is a list of typos it can find:
+//! - [A space after an opening bracket or before a closing bracket](`TypoSpaceInsideBrackets`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, looks_like_prose};
+
+/// A space *inside* parentheses or square brackets has been detected.
+///
+/// In English typography, there is no space after an opening parenthesis or bracket,
+/// nor before a closing one.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `( optional )`, should be `(optional)`
+/// - `[ default: 1 ]`, should be `[default: 1]`
+#[derive(Error, Debug, Diagnostic)]
+#[error("In English typography there is no space inside parentheses or brackets")]
+#[diagnostic(code("typope::space-inside-brackets"), url(docsrs))]
+pub struct TypoSpaceInsideBrackets {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Invalid space here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+}
+
+impl TypoSpaceInsideBrackets {
+    fn new(span: impl Into<SourceSpan>, bracket: char, after: bool) -> Self {
+        let position = if after { "after" } else { "before" };
+
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("remove the space {position} `{bracket}`"),
+        }
+    }
+}
+
+impl Typo for TypoSpaceInsideBrackets {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Remove { span: self.span }
+    }
+}
+
+/// A rule that detects spaces inside parentheses and square brackets.
+///
+/// Currently, it can only find and generate the following typo: [`TypoSpaceInsideBrackets`].
+///
+/// To avoid false positives, it only checks strings that look like prose,
+/// and it ignores shell tests (e.g., `[ -e file ]`), empty brackets (e.g., `[ ]`),
+/// and text aligned with spaces (e.g., a table).
+pub struct Brackets;
+
+impl Brackets {
+    /// Whether the text is aligned with several spaces or with pipes, like a table
+    fn is_aligned(text: &str) -> bool {
+        text.lines()
+            .any(|line| line.contains('|') || line.trim().contains("  "))
+    }
+
+    /// Whether the content of square brackets looks like a shell test (e.g., `[ -e file ]` or `[ "$a" = b ]`)
+    fn is_shell_test(content: &str) -> bool {
+        content
+            .trim_start_matches('[')
+            .trim_start()
+            .starts_with(['-', '!', '$', '"'])
+    }
+}
+
+impl Rule for Brackets {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) || Self::is_aligned(text) {
+            return Vec::new();
+        }
+
+        let mut typos: Vec<Box<dyn Typo>> = Vec::new();
+        let mut stack = Vec::new();
+        for (index, c) in text.char_indices() {
+            let opening = match c {
+                '(' | '[' => {
+                    stack.push((index, c));
+                    continue;
+                }
+                ')' => '(',
+                ']' => '[',
+                _ => continue,
+            };
+            let Some(position) = stack.iter().rposition(|(_, open)| *open == opening) else {
+                continue;
+            };
+            let Some((start, open)) = stack.drain(position..).next() else {
+                continue;
+            };
+
+            let Some(content) = text.get(start + 1..index) else {
+                continue;
+            };
+            if content.trim().is_empty() || (open == '[' && Self::is_shell_test(content)) {
+                continue;
+            }
+
+            let leading = content.len() - content.trim_start().len();
+            let leading_space = content.get(..leading).unwrap_or_default();
+            if leading > 0 && !leading_space.contains('\n') {
+                typos.push(Box::new(TypoSpaceInsideBrackets::new(
+                    (start + 1, leading),
+                    open,
+                    true,
+                )));
+            }
+
+            let trailing = content.len() - content.trim_end().len();
+            let trailing_space = content.get(content.len() - trailing..).unwrap_or_default();
+            if trailing > 0 && !trailing_space.contains('\n') {
+                typos.push(Box::new(TypoSpaceInsideBrackets::new(
+                    (index - trailing, trailing),
+                    c,
+                    false,
+                )));
+            }
+        }
+        typos.sort_by_key(|typo| typo.span().offset());
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::lint::{Fix, Rule};
+
+    use super::Brackets;
+
+    #[test]
+    fn empty() {
+        assert!(Brackets.check(br"").is_empty());
+    }
+
+    #[test]
+    fn no_spaces() {
+        assert!(
+            Brackets
+                .check(br"an argument (optional) [default: 1]")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn spaces() {
+        let mut typos = Brackets.check(br"an argument ( optional ) [default: 1 ]");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (36, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "remove the space before `]`"
+        );
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (22, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (13, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "remove the space after `(`"
+        );
+        assert!(matches!(typo.fix(), Fix::Remove { .. }));
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn nested() {
+        let mut typos = Brackets.check(br"see the docs (section [ usage ]) for details");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (29, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (23, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn shell_tests() {
+        assert!(Brackets.check(br"run [ -e /some/file ] first").is_empty());
+        assert!(Brackets.check(br"run [ ! -d some/dir ] first").is_empty());
+        assert!(
+            Brackets
+                .check(br#"run [[ "$value" = foo ]] first"#)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn ignored() {
+        assert!(Brackets.check(br"- [ ] todo item").is_empty());
+        assert!(Brackets.check(b"a list (\n    of items\n)").is_empty());
+        assert!(Brackets.check(b"| name ( unit ) | value |").is_empty());
+        assert!(
+            Brackets
+                .check(b"name ( unit )     value\nother ( unit ) value")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Typographical mistakes related to the capitalization of sentences.
+//!
+//! Here is a list of typos it can find:
+//! - [A sentence that starts with a lowercase letter](`TypoLowercaseSentenceStart`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, looks_like_prose};
+
+/// Abbreviations that end with a period but that do not end a sentence
+const ABBREVIATIONS: &[&str] = &[
+    "al.", "approx.", "ca.", "cf.", "e.g.", "etc.", "i.e.", "resp.", "viz.", "vs.",
+];
+
+/// A sentence starts with a lowercase letter after a sentence-ending punctuation mark.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `Done. next step`, should be `Done. Next step`
+/// - `Failed! please retry`, should be `Failed! Please retry`
+#[derive(Error, Debug, Diagnostic)]
+#[error("A sentence should start with an uppercase letter")]
+#[diagnostic(code("typope::lowercase-sentence-start"), url(docsrs))]
+pub struct TypoLowercaseSentenceStart {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Lowercase letter here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoLowercaseSentenceStart {
+    fn new(span: impl Into<SourceSpan>, letter: char) -> Self {
+        let replacement = letter.to_uppercase().collect::<String>();
+
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("replace `{letter}` with `{replacement}`"),
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoLowercaseSentenceStart {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// A rule that detects sentences starting with a lowercase letter after `.`, `!`, or `?`.
+///
+/// Currently, it can only find and generate the following typo: [`TypoLowercaseSentenceStart`].
+///
+/// To avoid false positives, it only checks strings that look like prose, and it ignores
+/// abbreviations (e.g., `e.g.` or `etc.`), ellipses, numbers, and words that look like
+/// file names or identifiers (e.g., `config.toml`, `foo_bar`, or `iOS`).
+/// The list of abbreviations can be extended with the configuration
+/// (e.g., `extend-abbreviations = ["Fig."]`).
+pub struct Capitalization {
+    abbreviations: Vec<String>,
+}
+
+impl Capitalization {
+    /// Creates the rule with additional abbreviations that do not end a sentence
+    pub fn new(abbreviations: &[String]) -> Self {
+        Self {
+            abbreviations: abbreviations
+                .iter()
+                .map(|abbreviation| abbreviation.to_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Whether the word before a sentence-ending punctuation mark actually ends a sentence
+    fn ends_sentence(&self, word: &str) -> bool {
+        let word = word.trim_start_matches(['(', '[', '"', '\'', '“', '‘']);
+        let lowercase = word.to_lowercase();
+        if ABBREVIATIONS.contains(&lowercase.as_str()) || self.abbreviations.contains(&lowercase) {
+            return false;
+        }
+
+        let Some(body) = word.get(..word.len().saturating_sub(1)) else {
+            return false;
+        };
+        // Ellipses (e.g., `Loading...`), list markers (e.g., `a.`), numbers (e.g., `1.2.`),
+        // or file names (e.g., `foo/`)
+        body.chars().filter(|c| c.is_alphabetic()).count() >= 2
+            && !body.ends_with(['.', '!', '?'])
+            && !body.contains(|c: char| c.is_ascii_digit() || "/\\_`".contains(c))
+    }
+
+    /// Whether the word can start a sentence, i.e., it is not a file name or an identifier
+    fn is_plain_word(word: &str) -> bool {
+        let word = word.trim_end_matches(|c: char| ",.;:!?)\"”'’".contains(c));
+        let mut chars = word.chars();
+
+        chars.next().is_some_and(char::is_lowercase)
+            && chars.all(|c| c.is_lowercase() || c == '-' || c == '\'' || c == '’')
+    }
+}
+
+impl Rule for Capitalization {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        let mut typos = Vec::new();
+        let mut previous: Option<&str> = None;
+        let mut offset = 0;
+        for chunk in text.split_inclusive(char::is_whitespace) {
+            let start = offset;
+            offset += chunk.len();
+            let word = chunk.trim_end();
+            if word.is_empty() {
+                continue;
+            }
+
+            let after_sentence = previous.is_some_and(|previous| {
+                previous.ends_with(['.', '!', '?']) && self.ends_sentence(previous)
+            });
+            previous = Some(word);
+            if !after_sentence || !Self::is_plain_word(word) {
+                continue;
+            }
+
+            if let Some(letter) = word.chars().next() {
+                typos.push(Box::new(TypoLowercaseSentenceStart::new(
+                    (start, letter.len_utf8()),
+                    letter,
+                )) as Box<dyn Typo>);
+            }
+        }
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::lint::{Fix, Rule};
+
+    use super::Capitalization;
+
+    #[test]
+    fn empty() {
+        assert!(Capitalization::new(&[]).check(br"").is_empty());
+    }
+
+    #[test]
+    fn correct() {
+        let rule = Capitalization::new(&[]);
+        assert!(rule.check(br"Done. Next step").is_empty());
+        assert!(rule.check(br"Is it done? Yes it is! Great").is_empty());
+        assert!(rule.check(br"Failed, please retry").is_empty());
+    }
+
+    #[test]
+    fn lowercase() {
+        let mut typos = Capitalization::new(&[]).check(br"Done. next step! please retry? ok");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (31, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (17, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (6, 1).into());
+        assert_eq!(typo.help().unwrap().to_string(), "replace `n` with `N`");
+        let Fix::Replace { replacement, .. } = typo.fix() else {
+            unreachable!()
+        };
+        assert_eq!(replacement, "N");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn abbreviations() {
+        let rule = Capitalization::new(&[]);
+        assert!(
+            rule.check(br"some tools, e.g. rustfmt, etc. and others")
+                .is_empty()
+        );
+        assert!(rule.check(br"Tabs vs. spaces, i.e. a debate").is_empty());
+        assert!(rule.check(br"It weighs approx. ten tons").is_empty());
+        assert_eq!(rule.check(br"It is nr. one").len(), 1);
+
+        let rule = Capitalization::new(&["NR.".to_owned()]);
+        assert!(rule.check(br"It is nr. one").is_empty());
+
+        assert_eq!(
+            Capitalization::new(&[])
+                .check(br"As shown in Fig. three, it works")
+                .len(),
+            1
+        );
+        let rule = Capitalization::new(&["Fig.".to_owned()]);
+        assert!(rule.check(br"As shown in Fig. three, it works").is_empty());
+    }
+
+    #[test]
+    fn not_sentences() {
+        let rule = Capitalization::new(&[]);
+        assert!(rule.check(br"Loading... please wait").is_empty());
+        assert!(
+            rule.check(br"Update to version 1.2. then restart")
+                .is_empty()
+        );
+        assert!(rule.check(br"Choose either a. this or b. that").is_empty());
+        assert!(
+            rule.check(br"Open the file. config.toml is there")
+                .is_empty()
+        );
+        assert!(rule.check(br"Set the value. foo_bar is used").is_empty());
+        assert!(
+            rule.check(br"Works on macOS. iOS is not supported")
+                .is_empty()
+        );
+        assert!(rule.check(br"Run `cargo test`. cargo is needed").is_empty());
+    }
+}
@@ -0,0 +1,544 @@
+//! Typographical mistakes related to hyphens and dashes.
+//!
+//! Here is a list of typos it can find:
+//! - [A hyphen in a range of numbers](`TypoHyphenInNumberRange`)
+//! - [A hyphen used as a dash in a sentence](`TypoHyphenAsDash`)
+//! - [An em dash not spaced as configured](`TypoEmDashSpacing`)
+//! - [A non-ASCII dash when only ASCII is expected](`TypoNonAsciiDash`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::config::EmDash;
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, char_after, char_before};
+
+const EN_DASH: char = '–';
+const EM_DASH: char = '—';
+
+/// A hyphen is used in a range of numbers instead of an en dash.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `pages 10-20`, should be `pages 10–20`
+/// - `from 1-5 items`, should be `from 1–5 items`
+#[derive(Error, Debug, Diagnostic)]
+#[error("A range of numbers is written with an en dash")]
+#[diagnostic(code("typope::hyphen-in-number-range"), url(docsrs))]
+pub struct TypoHyphenInNumberRange {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Hyphen here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+}
+
+impl TypoHyphenInNumberRange {
+    fn new(span: impl Into<SourceSpan>) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("replace `-` with `{EN_DASH}`"),
+        }
+    }
+}
+
+impl Typo for TypoHyphenInNumberRange {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: EN_DASH.into(),
+        }
+    }
+}
+
+/// A hyphen surrounded by spaces is used to separate two parts of a sentence instead of an em dash.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `it works - most of the time`, should be `it works — most of the time`
+///   (or `it works—most of the time` with `em-dash = "unspaced"`)
+#[derive(Error, Debug, Diagnostic)]
+#[error("A hyphen does not separate two parts of a sentence")]
+#[diagnostic(code("typope::hyphen-as-dash"), url(docsrs))]
+pub struct TypoHyphenAsDash {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Hyphen here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoHyphenAsDash {
+    fn new(span: impl Into<SourceSpan>, replacement: impl Into<String>) -> Self {
+        let replacement = replacement.into();
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("use an em dash (`{replacement}`) instead"),
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoHyphenAsDash {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// An em dash is not spaced as configured with `em-dash`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `this—and that`, should be `this — and that` with `em-dash = "spaced"`
+/// - `this — and that`, should be `this—and that` with `em-dash = "unspaced"`
+#[derive(Error, Debug, Diagnostic)]
+#[error("The spacing around this em dash does not follow the configured style")]
+#[diagnostic(code("typope::em-dash-spacing"), url(docsrs))]
+pub struct TypoEmDashSpacing {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Em dash here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoEmDashSpacing {
+    fn new(span: impl Into<SourceSpan>, style: EmDash) -> Self {
+        let (help, replacement) = if style == EmDash::Unspaced {
+            (
+                format!("remove the spaces around `{EM_DASH}`"),
+                EM_DASH.to_string(),
+            )
+        } else {
+            (
+                format!("add spaces around `{EM_DASH}`"),
+                format!(" {EM_DASH} "),
+            )
+        };
+
+        Self {
+            src: None,
+            span: span.into(),
+            help,
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoEmDashSpacing {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// An en dash or an em dash is used while only ASCII characters are expected (`em-dash = "ascii"`).
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `pages 10–20`, should be `pages 10-20`
+/// - `this — and that`, should be `this - and that`
+#[derive(Error, Debug, Diagnostic)]
+#[error("Only ASCII characters are expected for dashes")]
+#[diagnostic(code("typope::non-ascii-dash"), url(docsrs))]
+pub struct TypoNonAsciiDash {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Non-ASCII dash here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: &'static str,
+}
+
+impl TypoNonAsciiDash {
+    fn new(span: impl Into<SourceSpan>, dash: char, replacement: &'static str) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("replace `{dash}` with `{replacement}`"),
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoNonAsciiDash {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.into(),
+        }
+    }
+}
+
+/// A rule that detects typographical mistakes related to hyphens and dashes.
+///
+/// It can find and generate the following typos:
+/// - [`TypoHyphenInNumberRange`]
+/// - [`TypoHyphenAsDash`]
+/// - [`TypoEmDashSpacing`]
+/// - [`TypoNonAsciiDash`]
+///
+/// Command-line flags (e.g., `-v` or `--force`) and negative numbers are not considered as dashes.
+pub struct Dash {
+    em_dash: Option<EmDash>,
+}
+
+impl Dash {
+    /// Creates the rule with the expected style of em dashes.
+    ///
+    /// Without a style, hyphens used as dashes are reported, but the spacing around em dashes is not checked.
+    pub fn new(em_dash: Option<EmDash>) -> Self {
+        Self { em_dash }
+    }
+
+    fn number_ranges(text: &str) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.match_indices('-').filter_map(|(hyphen, _)| {
+            let before = text.get(..hyphen)?;
+            let start = hyphen - before.bytes().rev().take_while(u8::is_ascii_digit).count();
+            let after = text.get(hyphen + 1..)?;
+            let end = hyphen + 1 + after.bytes().take_while(u8::is_ascii_digit).count();
+
+            let from = text.get(start..hyphen)?;
+            let to = text.get(hyphen + 1..end)?;
+            if from.is_empty() || to.is_empty() || to.starts_with('0') {
+                return None;
+            }
+            // Avoid codes with leading zeros or phone numbers (e.g., `07-12` or `555-1234`)
+            if (from.len() > 1 && from.starts_with('0')) || (from.len() == 3 && to.len() == 4) {
+                return None;
+            }
+
+            // Avoid identifiers, versions, dates, or negative numbers (e.g., `x86-64`, `1.2-3`, `2024-10-20`, or `-1-2`)
+            if char_before(text, start)
+                .is_some_and(|c| c.is_alphanumeric() || "-_.,/:+#[\\".contains(c))
+            {
+                return None;
+            }
+            if let Some(c) = char_after(text, end) {
+                if c.is_alphanumeric() || "-_/:\\".contains(c) {
+                    return None;
+                }
+                if c == '.' && char_after(text, end + 1).is_some_and(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+            }
+
+            let (Ok(from), Ok(to)) = (from.parse::<u64>(), to.parse::<u64>()) else {
+                return None;
+            };
+            if from >= to {
+                return None;
+            }
+
+            Some(Box::new(TypoHyphenInNumberRange::new((hyphen, 1))) as Box<dyn Typo>)
+        })
+    }
+
+    fn hyphens_as_dash(text: &str, unspaced: bool) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.match_indices(" - ")
+            .filter_map(move |(start, pattern)| {
+                let end = start + pattern.len();
+                // Single letters around the hyphen are likely the operands of a subtraction (e.g., `x - y`)
+                let before = text.get(..start)?;
+                let after = text.get(end..)?;
+                let is_word = |word: &mut dyn Iterator<Item = char>| {
+                    word.take_while(|c| c.is_alphabetic()).take(2).count() == 2
+                };
+                if !is_word(&mut before.chars().rev()) || !is_word(&mut after.chars()) {
+                    return None;
+                }
+
+                let typo = if unspaced {
+                    TypoHyphenAsDash::new((start, pattern.len()), EM_DASH)
+                } else {
+                    TypoHyphenAsDash::new((start + 1, 1), EM_DASH)
+                };
+
+                Some(Box::new(typo) as Box<dyn Typo>)
+            })
+    }
+
+    fn em_dash_spacing(text: &str, style: EmDash) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.match_indices(EM_DASH)
+            .filter_map(move |(start, dash)| {
+                let end = start + dash.len();
+                let span = match style {
+                    EmDash::Spaced => {
+                        if !char_before(text, start).is_some_and(char::is_alphanumeric)
+                            || !char_after(text, end).is_some_and(char::is_alphanumeric)
+                        {
+                            return None;
+                        }
+
+                        (start, dash.len())
+                    }
+                    EmDash::Unspaced => {
+                        if char_before(text, start) != Some(' ')
+                            || char_after(text, end) != Some(' ')
+                        {
+                            return None;
+                        }
+                        if !char_before(text, start - 1).is_some_and(char::is_alphanumeric)
+                            || !char_after(text, end + 1).is_some_and(char::is_alphanumeric)
+                        {
+                            return None;
+                        }
+
+                        (start - 1, dash.len() + 2)
+                    }
+                    EmDash::Ascii => return None,
+                };
+
+                Some(Box::new(TypoEmDashSpacing::new(span, style)) as Box<dyn Typo>)
+            })
+    }
+
+    fn non_ascii_dashes(text: &str) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.match_indices([EN_DASH, EM_DASH])
+            .filter_map(move |(start, dash)| {
+                let dash_char = dash.chars().next()?;
+                let spaced = char_before(text, start).is_some_and(char::is_whitespace)
+                    || char_after(text, start + dash.len()).is_some_and(char::is_whitespace);
+                let replacement = if dash_char == EM_DASH && !spaced {
+                    " - "
+                } else {
+                    "-"
+                };
+
+                Some(Box::new(TypoNonAsciiDash::new(
+                    (start, dash.len()),
+                    dash_char,
+                    replacement,
+                )) as Box<dyn Typo>)
+            })
+    }
+}
+
+impl Rule for Dash {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+
+        let mut typos = Vec::new();
+        match self.em_dash {
+            Some(EmDash::Ascii) => {
+                typos.extend(Self::non_ascii_dashes(text));
+            }
+            style => {
+                typos.extend(Self::number_ranges(text));
+                typos.extend(Self::hyphens_as_dash(text, style == Some(EmDash::Unspaced)));
+                if let Some(style) = style {
+                    typos.extend(Self::em_dash_spacing(text, style));
+                }
+            }
+        }
+        typos.sort_by_key(|typo| typo.span().offset());
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::EmDash;
+    use crate::lint::{Fix, Rule};
+
+    use super::Dash;
+
+    fn replacement(fix: Fix) -> Option<String> {
+        let Fix::Replace { replacement, .. } = fix else {
+            return None;
+        };
+
+        Some(replacement)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Dash::new(None).check(br"").is_empty());
+    }
+
+    #[test]
+    fn number_range() {
+        let mut typos = Dash::new(None).check(b"see pages 10-20 or 1-5");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (20, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (12, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "–");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn number_range_start_of_string() {
+        let mut typos = Dash::new(None).check(b"1-10 items");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (1, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn not_a_number_range() {
+        let dash = Dash::new(None);
+        assert!(dash.check(b"x86-64").is_empty());
+        assert!(dash.check(b"released on 2024-10-20").is_empty());
+        assert!(dash.check(b"version 1.2-3").is_empty());
+        assert!(dash.check(b"between -10 and 10").is_empty());
+        assert!(dash.check(b"compute -1-2").is_empty());
+        assert!(dash.check(b"[0-9]+").is_empty());
+        assert!(dash.check(b"score of 3-2").is_empty());
+        assert!(dash.check(b"ISO-8859-1").is_empty());
+        assert!(dash.check(b"call 555-1234-56").is_empty());
+        assert!(dash.check(b"Call 555-1234 now").is_empty());
+        assert!(dash.check(b"open from 07-12").is_empty());
+        assert!(dash.check(b"between 20-10 items").is_empty());
+    }
+
+    #[test]
+    fn hyphen_as_dash() {
+        let mut typos = Dash::new(None).check(b"it works - most of the time");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "—");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn hyphen_as_dash_unspaced() {
+        let mut typos = Dash::new(Some(EmDash::Unspaced)).check(b"it works - most of the time");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (8, 3).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "—");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn command_line_flags() {
+        let dash = Dash::new(None);
+        assert!(dash.check(b"run with -v or --force").is_empty());
+        assert!(dash.check(b"cargo run -- --help").is_empty());
+        assert!(dash.check(b"- a list item").is_empty());
+        assert!(dash.check(b"x = 5 - 3").is_empty());
+        assert!(dash.check(b"Compute x - y here.").is_empty());
+        assert!(dash.check(b"the value a - b is positive").is_empty());
+    }
+
+    #[test]
+    fn em_dash_spaced() {
+        let dash = Dash::new(Some(EmDash::Spaced));
+        assert!(dash.check("this — and that".as_bytes()).is_empty());
+
+        let mut typos = dash.check("this—and that".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (4, 3).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), " — ");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn em_dash_unspaced() {
+        let dash = Dash::new(Some(EmDash::Unspaced));
+        assert!(dash.check("this—and that".as_bytes()).is_empty());
+
+        let mut typos = dash.check("this — and that".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (4, 5).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "—");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn em_dash_without_style() {
+        let dash = Dash::new(None);
+        assert!(dash.check("this—and that".as_bytes()).is_empty());
+        assert!(dash.check("this — and that".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn ascii() {
+        let dash = Dash::new(Some(EmDash::Ascii));
+        assert!(
+            dash.check(b"pages 10-20, it works - most of the time")
+                .is_empty()
+        );
+
+        let mut typos = dash.check("pages 10–20, this — and this—that".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (32, 3).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), " - ");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (20, 3).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "-");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (8, 3).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "-");
+        assert!(typos.is_empty());
+    }
+}
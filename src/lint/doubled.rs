@@ -0,0 +1,291 @@
+//! Typographical mistakes related to punctuation marks that are accidentally repeated.
+//!
+//! Here is a list of typos it can find:
+//! - [A doubled punctuation mark](`TypoDoubledPunctuation`)
+//! - [Too many consecutive exclamation or question marks](`TypoRepeatedMarks`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, char_after, char_before, looks_like_prose};
+
+/// Maximum number of consecutive exclamation or question marks by default
+const DEFAULT_MAX_REPEATED_MARKS: usize = 3;
+
+/// A punctuation mark has been doubled or is followed by a redundant period.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `first,, second`, should be `first, second`
+/// - `Done.. Next`, should be `Done. Next`
+/// - `Note:: this`, should be `Note: this`
+/// - `Really?.`, should be `Really?`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This punctuation mark is repeated")]
+#[diagnostic(code("typope::doubled-punctuation"), url(docsrs))]
+pub struct TypoDoubledPunctuation {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Redundant `{mark}` here")]
+    span: SourceSpan,
+
+    mark: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoDoubledPunctuation {
+    fn new(span: impl Into<SourceSpan>, mark: char) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            mark,
+            help: format!("remove the redundant `{mark}`"),
+        }
+    }
+}
+
+impl Typo for TypoDoubledPunctuation {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Remove { span: self.span }
+    }
+}
+
+/// Too many exclamation or question marks have been used in a row.
+///
+/// The maximum number of consecutive marks can be defined in the configuration with
+/// `max-repeated-marks` (3 by default).
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `What?!?!`, should be `What?!?`
+/// - `Stop!!!!!`, should be `Stop!!!`
+#[derive(Error, Debug, Diagnostic)]
+#[error("Too many exclamation or question marks")]
+#[diagnostic(code("typope::repeated-marks"), url(docsrs))]
+pub struct TypoRepeatedMarks {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Extra marks here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+}
+
+impl TypoRepeatedMarks {
+    fn new(span: impl Into<SourceSpan>, max: usize) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("use at most {max} consecutive marks"),
+        }
+    }
+}
+
+impl Typo for TypoRepeatedMarks {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Remove { span: self.span }
+    }
+}
+
+/// A rule that detects punctuation marks that are accidentally repeated.
+///
+/// It can find and generate the following typos:
+/// - [`TypoDoubledPunctuation`]
+/// - [`TypoRepeatedMarks`]
+///
+/// To avoid false positives, it only checks strings that look like prose,
+/// and a doubled mark must end a word (e.g., it ignores `1..10`, `../path`,
+/// `std::fs`, `fe80::1`, or an ellipsis).
+pub struct Doubled {
+    max_repeated_marks: usize,
+}
+
+impl Doubled {
+    /// Creates the rule with the maximum number of consecutive exclamation or question marks
+    pub fn new(max_repeated_marks: Option<usize>) -> Self {
+        Self {
+            max_repeated_marks: max_repeated_marks.unwrap_or(DEFAULT_MAX_REPEATED_MARKS),
+        }
+    }
+
+    /// Finds `,,`, `..`, `;;`, and `::` at the end of a word
+    fn doubled_marks(text: &str) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.char_indices().filter_map(|(index, c)| {
+            if !",.;:".contains(c) || char_after(text, index + 1) != Some(c) {
+                return None;
+            }
+
+            let before = char_before(text, index)?;
+            let ends_word = char_after(text, index + 2).is_none_or(char::is_whitespace);
+            if !before.is_alphanumeric() || !ends_word {
+                return None;
+            }
+
+            // IPv6 addresses (e.g., `fe80::`)
+            let word = text.get(..index)?.split_whitespace().next_back()?;
+            if c == ':' && !word.chars().all(char::is_alphabetic) {
+                return None;
+            }
+
+            Some(Box::new(TypoDoubledPunctuation::new((index + 1, 1), c)) as _)
+        })
+    }
+
+    /// Finds a period after `?` or `!` (e.g., `Really?.`)
+    fn redundant_periods(text: &str) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.char_indices().filter_map(|(index, c)| {
+            if c != '.' || !matches!(char_before(text, index), Some('?' | '!')) {
+                return None;
+            }
+            if !char_after(text, index + 1).is_none_or(char::is_whitespace) {
+                return None;
+            }
+
+            Some(Box::new(TypoDoubledPunctuation::new((index, 1), c)) as _)
+        })
+    }
+
+    /// Finds sequences of `!` and `?` longer than the maximum
+    fn repeated_marks(text: &str, max: usize) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        let mut run_start = None;
+
+        text.char_indices()
+            .chain(std::iter::once((text.len(), ' ')))
+            .filter_map(move |(index, c)| {
+                if matches!(c, '!' | '?') {
+                    run_start.get_or_insert(index);
+                    return None;
+                }
+
+                let start = run_start.take()?;
+                if index - start <= max {
+                    return None;
+                }
+
+                Some(Box::new(TypoRepeatedMarks::new(
+                    (start + max, index - start - max),
+                    max,
+                )) as _)
+            })
+    }
+}
+
+impl Rule for Doubled {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        let mut typos = Self::doubled_marks(text)
+            .chain(Self::redundant_periods(text))
+            .chain(Self::repeated_marks(text, self.max_repeated_marks))
+            .collect::<Vec<_>>();
+        typos.sort_by_key(|typo| typo.span().offset());
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::lint::{Fix, Rule};
+
+    use super::Doubled;
+
+    #[test]
+    fn empty() {
+        assert!(Doubled::new(None).check(br"").is_empty());
+    }
+
+    #[test]
+    fn doubled() {
+        let mut typos = Doubled::new(None).check(br"first,, second; third;; Done.. Note:: this");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (36, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (29, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (22, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (6, 1).into());
+        assert_eq!(typo.help().unwrap().to_string(), "remove the redundant `,`");
+        assert!(matches!(typo.fix(), Fix::Remove { .. }));
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn not_doubled() {
+        let rule = Doubled::new(None);
+        assert!(rule.check(br"Loading... please wait").is_empty());
+        assert!(rule.check(br"values in 1..10 are valid").is_empty());
+        assert!(rule.check(br"go to ../path for details").is_empty());
+        assert!(rule.check(br"use std::fs to read a file").is_empty());
+        assert!(rule.check(br"listen on fe80:: or ::1 instead").is_empty());
+        assert!(rule.check(br"a,,b is not valid").is_empty());
+    }
+
+    #[test]
+    fn redundant_period() {
+        let mut typos = Doubled::new(None).check(br"Really?. I did not know!.");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (24, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 1).into());
+        assert!(typos.is_empty());
+
+        assert!(Doubled::new(None).check(br"Really?... yes").is_empty());
+    }
+
+    #[test]
+    fn repeated_marks() {
+        let rule = Doubled::new(None);
+        assert!(rule.check(br"What?! Really??? Yes!!!").is_empty());
+
+        let mut typos = rule.check(br"What?!?! Stop!!!!!");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (16, 2).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "use at most 3 consecutive marks"
+        );
+        assert!(typos.is_empty());
+
+        let rule = Doubled::new(Some(1));
+        assert_eq!(rule.check(br"What?! Really??").len(), 2);
+    }
+}
@@ -0,0 +1,338 @@
+//! Typographical mistakes related to the style of error messages.
+//!
+//! Here is a list of typos it can find:
+//! - [An error message that does not start with the expected case](`TypoErrorMessageCase`)
+//! - [An error message with or without a trailing period](`TypoErrorMessagePeriod`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::config::ErrorMessageCase;
+use crate::lang::{Kind, Lintable};
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo};
+
+/// An error message does not start with the expected case.
+///
+/// The expected case is defined in the configuration with `error-message-case`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `bail!("Failed to open the file")`, should be `bail!("failed to open the file")` with `lowercase`
+/// - `raise ValueError("invalid value.")`, should be `raise ValueError("Invalid value.")` with `uppercase`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This error message does not start with the expected case")]
+#[diagnostic(code("typope::error-message-case"), url(docsrs))]
+pub struct TypoErrorMessageCase {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Invalid case here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoErrorMessageCase {
+    fn new(span: impl Into<SourceSpan>, case: ErrorMessageCase, letter: char) -> Self {
+        let (replacement, help) = match case {
+            ErrorMessageCase::Lowercase => (
+                letter.to_lowercase().collect(),
+                "error messages should start with a lowercase letter",
+            ),
+            ErrorMessageCase::Uppercase => (
+                letter.to_uppercase().collect(),
+                "error messages should start with an uppercase letter",
+            ),
+        };
+
+        Self {
+            src: None,
+            span: span.into(),
+            help: help.into(),
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoErrorMessageCase {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// An error message ends with a period while it should not, or the other way around.
+///
+/// Whether a period is expected is defined in the configuration with `error-message-period`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `errors.New("invalid value.")`, should be `errors.New("invalid value")` with `false`
+/// - `raise ValueError("Invalid value")`, should be `raise ValueError("Invalid value.")` with `true`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This error message does not end as expected")]
+#[diagnostic(code("typope::error-message-period"), url(docsrs))]
+pub struct TypoErrorMessagePeriod {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("{label}")]
+    span: SourceSpan,
+
+    label: &'static str,
+
+    #[help]
+    help: &'static str,
+
+    period: bool,
+}
+
+impl TypoErrorMessagePeriod {
+    fn new(span: impl Into<SourceSpan>, period: bool) -> Self {
+        let (label, help) = if period {
+            (
+                "Missing period here",
+                "error messages should end with a period",
+            )
+        } else {
+            (
+                "Trailing period here",
+                "error messages should not end with a period",
+            )
+        };
+
+        Self {
+            src: None,
+            span: span.into(),
+            label,
+            help,
+            period,
+        }
+    }
+}
+
+impl Typo for TypoErrorMessagePeriod {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        if self.period {
+            Fix::Replace {
+                span: self.span,
+                replacement: ".".into(),
+            }
+        } else {
+            Fix::Remove { span: self.span }
+        }
+    }
+}
+
+/// A rule that enforces the style of error messages.
+///
+/// It can find and generate the following typos:
+/// - [`TypoErrorMessageCase`]
+/// - [`TypoErrorMessagePeriod`]
+///
+/// It only applies to strings given to a function or a macro that builds an error
+/// (e.g., `anyhow::bail!`, `Err(...)`, `panic!`, `raise ValueError(...)`, `errors.New`, or `fmt.Errorf`),
+/// and only when a style has been configured, usually per file type:
+///
+/// ```toml
+/// [type.rust]
+/// error-message-case = "lowercase"
+/// error-message-period = false
+///
+/// [type.python]
+/// error-message-case = "uppercase"
+/// error-message-period = true
+/// ```
+pub struct ErrorMessage {
+    case: Option<ErrorMessageCase>,
+    period: Option<bool>,
+}
+
+impl ErrorMessage {
+    /// Creates the rule with the expected case and whether a trailing period is expected
+    pub fn new(case: Option<ErrorMessageCase>, period: Option<bool>) -> Self {
+        Self { case, period }
+    }
+
+    /// Returns the message without the quotation marks that surround it, with its offset
+    fn unquote(text: &str) -> (usize, &str) {
+        for quote in ['"', '\'', '`'] {
+            if let Some(message) = text
+                .strip_prefix(quote)
+                .and_then(|text| text.strip_suffix(quote))
+            {
+                return (quote.len_utf8(), message);
+            }
+        }
+
+        (0, text)
+    }
+
+    fn case(&self, offset: usize, message: &str) -> Option<Box<dyn Typo>> {
+        let case = self.case?;
+        let word = message.split_whitespace().next()?;
+        let word = word.trim_end_matches([',', '.', ':', ';', '!', '?']);
+        let mut chars = word.chars();
+        let first = chars.next()?;
+        let rest = chars.as_str();
+
+        // Only plain words are checked to ignore acronyms and identifiers (e.g., `HTTP` or `foo_bar`)
+        let is_plain = !rest.is_empty() && rest.chars().all(|c| c.is_lowercase() || c == '\'');
+        let is_invalid = match case {
+            ErrorMessageCase::Lowercase => first.is_uppercase(),
+            ErrorMessageCase::Uppercase => first.is_lowercase(),
+        };
+        if !is_plain || !is_invalid || !message.starts_with(first) {
+            return None;
+        }
+
+        Some(Box::new(TypoErrorMessageCase::new(
+            (offset, first.len_utf8()),
+            case,
+            first,
+        )))
+    }
+
+    fn period(&self, offset: usize, message: &str) -> Option<Box<dyn Typo>> {
+        let period = self.period?;
+        let last = message.chars().next_back()?;
+
+        if period && last.is_alphanumeric() {
+            Some(Box::new(TypoErrorMessagePeriod::new(
+                (offset + message.len(), 0),
+                true,
+            )))
+        } else if !period && last == '.' && !message.ends_with("..") {
+            Some(Box::new(TypoErrorMessagePeriod::new(
+                (offset + message.len() - 1, 1),
+                false,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+impl Rule for ErrorMessage {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        let (offset, message) = Self::unquote(text);
+
+        self.case(offset, message)
+            .into_iter()
+            .chain(self.period(offset, message))
+            .collect()
+    }
+
+    fn check_lintable(&self, lintable: &Lintable) -> Vec<Box<dyn Typo>> {
+        if lintable.kind() != Kind::ErrorMessage {
+            return Vec::new();
+        }
+
+        self.check(lintable.as_str().as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::config::ErrorMessageCase;
+    use crate::lang::{Kind, Lintable, LintableString};
+    use crate::lint::{Fix, Rule};
+
+    use super::ErrorMessage;
+
+    #[test]
+    fn empty() {
+        let rule = ErrorMessage::new(Some(ErrorMessageCase::Lowercase), Some(false));
+        assert!(rule.check(br"").is_empty());
+    }
+
+    #[test]
+    fn not_configured() {
+        let rule = ErrorMessage::new(None, None);
+        assert!(rule.check(br"Failed to open the file.").is_empty());
+    }
+
+    #[test]
+    fn lowercase_without_period() {
+        let rule = ErrorMessage::new(Some(ErrorMessageCase::Lowercase), Some(false));
+        assert!(rule.check(br"failed to open the file").is_empty());
+        assert!(rule.check(br"HTTP request failed").is_empty());
+        assert!(rule.check(br"loading...").is_empty());
+
+        let mut typos = rule.check(br"Failed to open the file.");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (23, 1).into());
+        assert!(matches!(typo.fix(), Fix::Remove { .. }));
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (0, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "error messages should start with a lowercase letter"
+        );
+        let Fix::Replace { replacement, .. } = typo.fix() else {
+            unreachable!()
+        };
+        assert_eq!(replacement, "f");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn uppercase_with_period() {
+        let rule = ErrorMessage::new(Some(ErrorMessageCase::Uppercase), Some(true));
+        assert!(rule.check(br"Invalid value.").is_empty());
+        assert!(rule.check(br"foo_bar is not set.").is_empty());
+        assert!(rule.check(br"Invalid value: ").is_empty());
+
+        let mut typos = rule.check(br#""invalid value""#);
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (14, 0).into());
+        let Fix::Replace { replacement, .. } = typo.fix() else {
+            unreachable!()
+        };
+        assert_eq!(replacement, ".");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (1, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn only_error_messages() {
+        let rule = ErrorMessage::new(Some(ErrorMessageCase::Lowercase), None);
+        let string = |kind| Lintable::new(LintableString::new(0, "Failed to open"), kind);
+        assert_eq!(rule.check_lintable(&string(Kind::ErrorMessage)).len(), 1);
+        assert!(rule.check_lintable(&string(Kind::Text)).is_empty());
+        assert!(rule.check_lintable(&string(Kind::Comment)).is_empty());
+    }
+}
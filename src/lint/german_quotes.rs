@@ -0,0 +1,240 @@
+//! Typographical mistakes related to the quotation marks used in German.
+//!
+//! Here is a list of typos it can find:
+//! - [A quotation mark that is not used in German](`TypoGermanQuotationMark`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::config::GermanQuoteStyle;
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, char_after, char_before, looks_like_prose};
+
+/// Double quotation marks that can open or close a quote
+const QUOTATION_MARKS: &[char] = &['"', '“', '”', '„', '«', '»'];
+
+/// A quotation mark that is not used in German has been detected.
+///
+/// In German, a quote is written `„…“` or, as an alternative, `»…«`.
+/// The expected style can be defined in the configuration with `german-quotes`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `"Hallo"`, should be `„Hallo“`
+/// - `“Hallo”`, should be `„Hallo“`
+/// - `«Hallo»`, should be `»Hallo«` with `guillemets`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This quotation mark is not used in German")]
+#[diagnostic(code("typope::german-quotation-mark"), url(docsrs))]
+pub struct TypoGermanQuotationMark {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Expected `{expected}` here")]
+    span: SourceSpan,
+
+    expected: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoGermanQuotationMark {
+    fn new(span: impl Into<SourceSpan>, found: char, expected: char) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            expected,
+            help: format!("replace `{found}` with `{expected}`"),
+        }
+    }
+}
+
+impl Typo for TypoGermanQuotationMark {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.expected.into(),
+        }
+    }
+}
+
+/// A rule that detects quotes that are not written with German quotation marks.
+///
+/// Currently, it can only find and generate the following typo: [`TypoGermanQuotationMark`].
+///
+/// It only applies when the locale is German, and it expects `„…“` by default:
+///
+/// ```toml
+/// [default]
+/// locale = "de"
+/// german-quotes = "guillemets"
+/// ```
+///
+/// To avoid false positives, it only checks strings that look like prose,
+/// and a quotation mark is only reported once the quote has been closed.
+pub struct GermanQuotes {
+    opening: char,
+    closing: char,
+}
+
+impl GermanQuotes {
+    /// Creates the rule with the style of the expected quotation marks
+    pub fn new(style: Option<GermanQuoteStyle>) -> Self {
+        let (opening, closing) = match style.unwrap_or(GermanQuoteStyle::LowHigh) {
+            GermanQuoteStyle::LowHigh => ('„', '“'),
+            GermanQuoteStyle::Guillemets => ('»', '«'),
+        };
+
+        Self { opening, closing }
+    }
+
+    /// Whether the mark at this index can open a quote (e.g., not an inch mark like `5"`)
+    fn can_open(text: &str, index: usize, mark: char) -> bool {
+        char_before(text, index).is_none_or(|c| !c.is_alphanumeric() && c != '\\')
+            && char_after(text, index + mark.len_utf8()).is_some_and(|c| !c.is_whitespace())
+    }
+
+    /// Whether the mark at this index can close a quote
+    fn can_close(text: &str, index: usize) -> bool {
+        char_before(text, index).is_some_and(|c| !c.is_whitespace() && c != '\\')
+    }
+}
+
+impl Rule for GermanQuotes {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        let mut typos: Vec<Box<dyn Typo>> = Vec::new();
+        let mut opened: Option<(usize, char)> = None;
+        for (index, c) in text.char_indices() {
+            if !QUOTATION_MARKS.contains(&c) {
+                continue;
+            }
+
+            match opened {
+                Some((opening_index, opening)) if Self::can_close(text, index) => {
+                    opened = None;
+                    if opening != self.opening {
+                        typos.push(Box::new(TypoGermanQuotationMark::new(
+                            (opening_index, opening.len_utf8()),
+                            opening,
+                            self.opening,
+                        )));
+                    }
+                    if c != self.closing {
+                        typos.push(Box::new(TypoGermanQuotationMark::new(
+                            (index, c.len_utf8()),
+                            c,
+                            self.closing,
+                        )));
+                    }
+                }
+                _ if Self::can_open(text, index, c) => {
+                    opened = Some((index, c));
+                }
+                _ => {}
+            }
+        }
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::config::GermanQuoteStyle;
+    use crate::lint::{Fix, Rule};
+
+    use super::GermanQuotes;
+
+    fn replacement(fix: Fix) -> Option<String> {
+        let Fix::Replace { replacement, .. } = fix else {
+            return None;
+        };
+
+        Some(replacement)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(GermanQuotes::new(None).check(br"").is_empty());
+    }
+
+    #[test]
+    fn correct() {
+        let rule = GermanQuotes::new(None);
+        assert!(
+            rule.check("Klicken Sie auf „Speichern“, um fortzufahren.".as_bytes())
+                .is_empty()
+        );
+
+        let rule = GermanQuotes::new(Some(GermanQuoteStyle::Guillemets));
+        assert!(
+            rule.check("Klicken Sie auf »Speichern«, um fortzufahren.".as_bytes())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn english_quotes() {
+        let mut typos =
+            GermanQuotes::new(None).check("Klicken Sie auf “Speichern” oder \"OK\".".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (40, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "“");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (37, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "„");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (28, 3).into());
+        assert_eq!(typo.help().unwrap().to_string(), "replace `”` with `“`");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (16, 3).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "„");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn guillemets() {
+        let rule = GermanQuotes::new(Some(GermanQuoteStyle::Guillemets));
+        let mut typos = rule.check("Er sagt «Hallo» und „tschüss“.".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(replacement(typo.fix()).unwrap(), "«");
+        let typo = typos.pop().unwrap();
+        assert_eq!(replacement(typo.fix()).unwrap(), "»");
+        let typo = typos.pop().unwrap();
+        assert_eq!(replacement(typo.fix()).unwrap(), "«");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (8, 2).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "»");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn ignored() {
+        let rule = GermanQuotes::new(None);
+        assert!(rule.check(br#"Ein 27" Bildschirm ist gut"#).is_empty());
+        assert!(rule.check(br#"Er sagt "Hallo und geht"#).is_empty());
+        assert!(rule.check(br#"Er sagt \"Hallo\" heute"#).is_empty());
+    }
+}
@@ -0,0 +1,377 @@
+//! Typographical mistakes related to the style of headings in documents.
+//!
+//! Here is a list of typos it can find:
+//! - [A heading that ends with a period or a colon](`TypoHeadingPunctuation`)
+//! - [A word of a heading that does not follow the expected case](`TypoHeadingCase`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::config::HeadingCase;
+use crate::lang::{Kind, Lintable};
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo};
+
+/// Words that are not capitalized in title case, unless they start the heading
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "nor", "of", "on",
+    "or", "per", "so", "the", "to", "up", "via", "vs", "with", "yet",
+];
+
+/// A heading ends with a period or a colon.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `# Installation.`, should be `# Installation`
+/// - `## Options:`, should be `## Options`
+#[derive(Error, Debug, Diagnostic)]
+#[error("A heading should not end with a period or a colon")]
+#[diagnostic(code("typope::heading-punctuation"), url(docsrs))]
+pub struct TypoHeadingPunctuation {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Invalid `{mark}` here")]
+    span: SourceSpan,
+
+    mark: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoHeadingPunctuation {
+    fn new(span: impl Into<SourceSpan>, mark: char) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            mark,
+            help: format!("remove the trailing `{mark}`"),
+        }
+    }
+}
+
+impl Typo for TypoHeadingPunctuation {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Remove { span: self.span }
+    }
+}
+
+/// A word of a heading does not follow the expected case.
+///
+/// The expected case is defined in the configuration with `heading-case`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `# Supported Languages`, should be `# Supported languages` with `sentence`
+/// - `# Supported languages`, should be `# Supported Languages` with `title`
+/// - `# Installation And Usage`, should be `# Installation and Usage` with `title`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This heading does not follow the expected case")]
+#[diagnostic(code("typope::heading-case"), url(docsrs))]
+pub struct TypoHeadingCase {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Invalid case here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoHeadingCase {
+    fn new(span: impl Into<SourceSpan>, case: HeadingCase, letter: char) -> Self {
+        let case = match case {
+            HeadingCase::Sentence => "sentence case",
+            HeadingCase::Title => "title case",
+        };
+        let (replacement, help) = if letter.is_uppercase() {
+            (
+                letter.to_lowercase().collect(),
+                format!("lowercase this word to follow {case}"),
+            )
+        } else {
+            (
+                letter.to_uppercase().collect(),
+                format!("capitalize this word to follow {case}"),
+            )
+        };
+
+        Self {
+            src: None,
+            span: span.into(),
+            help,
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoHeadingCase {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// A plain word of a heading, with its offset
+struct Word<'s> {
+    offset: usize,
+    first: char,
+    value: &'s str,
+}
+
+impl Word<'_> {
+    fn is_minor(&self) -> bool {
+        MINOR_WORDS.contains(&self.value.to_lowercase().as_str())
+    }
+}
+
+/// A rule that enforces the style of headings in documents.
+///
+/// It can find and generate the following typos:
+/// - [`TypoHeadingPunctuation`]
+/// - [`TypoHeadingCase`]
+///
+/// It only applies to headings (e.g., `# Title` or a setext heading in Markdown).
+/// The case of the words is only checked when it has been configured:
+///
+/// ```toml
+/// [type.markdown]
+/// heading-case = "sentence"
+/// ```
+///
+/// To avoid false positives, the first word, acronyms (e.g., `CLI`), and identifiers
+/// (e.g., `foo_bar` or `macOS`) are ignored. With sentence case, capitalized words are only
+/// reported when the whole heading is in title case, since they might be proper nouns.
+pub struct Heading {
+    case: Option<HeadingCase>,
+}
+
+impl Heading {
+    /// Creates the rule with the expected case of the headings
+    pub fn new(case: Option<HeadingCase>) -> Self {
+        Self { case }
+    }
+
+    /// Returns the plain words of the heading (e.g., not `foo_bar`, `v1`, or `HTTP`)
+    fn words(text: &str) -> impl Iterator<Item = Word<'_>> {
+        text.split_whitespace().filter_map(|word| {
+            let offset = word.as_ptr() as usize - text.as_ptr() as usize;
+            let trimmed = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+            let offset = offset + word.len() - trimmed.len();
+            let value = trimmed.trim_end_matches(|c: char| !c.is_alphanumeric());
+
+            let mut chars = value.chars();
+            let first = chars.next()?;
+            let is_plain =
+                first.is_alphabetic() && chars.all(|c| c.is_lowercase() || c == '-' || c == '\'');
+            is_plain.then_some(Word {
+                offset,
+                first,
+                value,
+            })
+        })
+    }
+
+    fn punctuation(text: &str) -> Option<Box<dyn Typo>> {
+        let trimmed = text.trim_end();
+        let mark = trimmed
+            .chars()
+            .next_back()
+            .filter(|c| matches!(c, '.' | ':'))?;
+        // An ellipsis (e.g., `And more...`)
+        if trimmed.ends_with("..") {
+            return None;
+        }
+
+        Some(Box::new(TypoHeadingPunctuation::new(
+            (trimmed.len() - 1, 1),
+            mark,
+        )))
+    }
+
+    fn case(&self, text: &str) -> Vec<Box<dyn Typo>> {
+        let Some(case) = self.case else {
+            return Vec::new();
+        };
+        // The first word is ignored, unless the heading has been cut around some code
+        // (e.g., a `<code>` element in HTML) so it does not start with this string
+        let first_word_end = if text.starts_with(char::is_whitespace) {
+            0
+        } else {
+            text.find(char::is_whitespace).unwrap_or(text.len())
+        };
+        let others = Self::words(text).filter(|word| word.offset >= first_word_end);
+
+        let invalid = match case {
+            HeadingCase::Title => others
+                .filter(|word| word.first.is_uppercase() == word.is_minor())
+                .collect::<Vec<_>>(),
+            HeadingCase::Sentence => {
+                let major = others.filter(|word| !word.is_minor()).collect::<Vec<_>>();
+                if !major.iter().all(|word| word.first.is_uppercase()) {
+                    return Vec::new();
+                }
+                major
+            }
+        };
+
+        invalid
+            .into_iter()
+            .map(|word| {
+                Box::new(TypoHeadingCase::new(
+                    (word.offset, word.first.len_utf8()),
+                    case,
+                    word.first,
+                )) as _
+            })
+            .collect()
+    }
+}
+
+impl Rule for Heading {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+
+        let mut typos = self.case(text);
+        typos.extend(Self::punctuation(text));
+
+        typos
+    }
+
+    fn check_lintable(&self, lintable: &Lintable) -> Vec<Box<dyn Typo>> {
+        if lintable.kind() != Kind::Heading {
+            return Vec::new();
+        }
+
+        self.check(lintable.as_str().as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::config::HeadingCase;
+    use crate::lang::{Kind, Lintable, LintableString};
+    use crate::lint::{Fix, Rule};
+
+    use super::Heading;
+
+    fn replacement(fix: Fix) -> Option<String> {
+        let Fix::Replace { replacement, .. } = fix else {
+            return None;
+        };
+
+        Some(replacement)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(
+            Heading::new(Some(HeadingCase::Title))
+                .check(br"")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn punctuation() {
+        let rule = Heading::new(None);
+        assert!(rule.check(br"Installation").is_empty());
+        assert!(rule.check(br"Why? And more...").is_empty());
+
+        let mut typos = rule.check(br"Supported Languages:");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (19, 1).into());
+        assert_eq!(typo.help().unwrap().to_string(), "remove the trailing `:`");
+        assert!(matches!(typo.fix(), Fix::Remove { .. }));
+        assert!(typos.is_empty());
+
+        assert_eq!(rule.check(br"Installation.").len(), 1);
+    }
+
+    #[test]
+    fn sentence_case() {
+        let rule = Heading::new(Some(HeadingCase::Sentence));
+        assert!(rule.check(br"Supported languages").is_empty());
+        assert!(rule.check(br"Install the CLI with cargo").is_empty());
+        assert!(rule.check(br"Using macOS and foo_bar").is_empty());
+
+        let mut typos = rule.check(br"Command Line Options");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (13, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (8, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "lowercase this word to follow sentence case"
+        );
+        assert_eq!(replacement(typo.fix()).unwrap(), "l");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn title_case() {
+        let rule = Heading::new(Some(HeadingCase::Title));
+        assert!(rule.check(br"Command Line Options").is_empty());
+        assert!(rule.check(br"Installation and Usage of the CLI").is_empty());
+        assert!(rule.check(br"typope on GitHub").is_empty());
+
+        let mut typos = rule.check(br"Installation And usage");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (17, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "capitalize this word to follow title case"
+        );
+        assert_eq!(replacement(typo.fix()).unwrap(), "U");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (13, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "a");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn cut_around_code() {
+        let rule = Heading::new(Some(HeadingCase::Title));
+        assert_eq!(rule.check(br" option").len(), 1);
+        assert!(rule.check(br"The ").is_empty());
+    }
+
+    #[test]
+    fn only_headings() {
+        let rule = Heading::new(None);
+        let string = |kind| Lintable::new(LintableString::new(0, "Installation."), kind);
+        assert_eq!(rule.check_lintable(&string(Kind::Heading)).len(), 1);
+        assert!(rule.check_lintable(&string(Kind::Text)).is_empty());
+    }
+}
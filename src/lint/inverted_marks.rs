@@ -0,0 +1,303 @@
+//! Typographical mistakes related to the inverted question and exclamation marks used in Spanish.
+//!
+//! Here is a list of typos it can find:
+//! - [A question or an exclamation without its opening mark](`TypoMissingInvertedMark`)
+//! - [An opening mark that does not match the closing one](`TypoMismatchedInvertedMark`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, char_after, looks_like_prose};
+
+/// Returns the opening mark expected for a closing one
+fn opening_for(closing: char) -> char {
+    if closing == '?' { '¿' } else { '¡' }
+}
+
+/// A question or an exclamation does not start with an inverted mark.
+///
+/// In Spanish, a question starts with `¿` and an exclamation starts with `¡`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `Qué pasa?`, should be `¿Qué pasa?`
+/// - `Hola, cómo estás?`, should be `Hola, ¿cómo estás?`
+#[derive(Error, Debug, Diagnostic)]
+#[error("In Spanish, a question or an exclamation starts with an inverted mark")]
+#[diagnostic(code("typope::missing-inverted-mark"), url(docsrs))]
+pub struct TypoMissingInvertedMark {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label(primary, "Expected `{opening}` here")]
+    span: SourceSpan,
+
+    #[label("Closed here")]
+    closing_span: SourceSpan,
+
+    opening: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoMissingInvertedMark {
+    fn new(
+        span: impl Into<SourceSpan>,
+        closing_span: impl Into<SourceSpan>,
+        closing: char,
+    ) -> Self {
+        let opening = opening_for(closing);
+
+        Self {
+            src: None,
+            span: span.into(),
+            closing_span: closing_span.into(),
+            opening,
+            help: format!("add `{opening}` at the start of the sentence"),
+        }
+    }
+}
+
+impl Typo for TypoMissingInvertedMark {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+        self.closing_span = lintable.source_span(self.closing_span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.opening.into(),
+        }
+    }
+}
+
+/// An inverted mark does not match the mark that closes the question or the exclamation.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `¿Qué!`, should be `¿Qué?`
+/// - `¡Hola?`, should be `¡Hola!`
+#[derive(Error, Debug, Diagnostic)]
+#[error("The opening and closing marks do not match")]
+#[diagnostic(code("typope::mismatched-inverted-mark"), url(docsrs))]
+pub struct TypoMismatchedInvertedMark {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label(primary, "Expected `{expected}` here")]
+    span: SourceSpan,
+
+    #[label("Opened here")]
+    opening_span: SourceSpan,
+
+    expected: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoMismatchedInvertedMark {
+    fn new(
+        span: impl Into<SourceSpan>,
+        opening_span: impl Into<SourceSpan>,
+        opening: char,
+    ) -> Self {
+        let expected = if opening == '¿' { '?' } else { '!' };
+
+        Self {
+            src: None,
+            span: span.into(),
+            opening_span: opening_span.into(),
+            expected,
+            help: format!("replace it with `{expected}` to match `{opening}`"),
+        }
+    }
+}
+
+impl Typo for TypoMismatchedInvertedMark {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+        self.opening_span = lintable.source_span(self.opening_span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.expected.into(),
+        }
+    }
+}
+
+/// A rule that detects questions and exclamations without their inverted marks in Spanish.
+///
+/// It can find and generate the following typos:
+/// - [`TypoMissingInvertedMark`]
+/// - [`TypoMismatchedInvertedMark`]
+///
+/// It only applies when the locale is Spanish:
+///
+/// ```toml
+/// [default]
+/// locale = "es"
+/// ```
+pub struct InvertedMarks;
+
+impl Rule for InvertedMarks {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        let mut typos: Vec<Box<dyn Typo>> = Vec::new();
+        // Where the current sentence starts, and the inverted mark that has been opened in it
+        let mut sentence_start = None;
+        let mut opened: Option<(usize, char)> = None;
+        let mut chars = text.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '¿' | '¡' => {
+                    opened = Some((index, c));
+                    sentence_start.get_or_insert(index);
+                }
+                '?' | '!' => {
+                    // Only the first mark of a sequence (e.g., `?!`) closes the sentence
+                    while chars.next_if(|(_, c)| matches!(c, '?' | '!')).is_some() {}
+                    let end = chars.peek().map_or(text.len(), |(index, _)| *index);
+                    let ends_sentence = char_after(text, end)
+                        .is_none_or(|c| c.is_whitespace() || "\"'”»).,".contains(c));
+                    if !ends_sentence {
+                        continue;
+                    }
+
+                    match (opened.take(), sentence_start.take()) {
+                        (Some((opening_index, opening)), _) if opening_for(c) != opening => {
+                            typos.push(Box::new(TypoMismatchedInvertedMark::new(
+                                (index, c.len_utf8()),
+                                (opening_index, opening.len_utf8()),
+                                opening,
+                            )));
+                        }
+                        (Some(_), _) | (None, None) => {}
+                        (None, Some(start)) => {
+                            typos.push(Box::new(TypoMissingInvertedMark::new(
+                                (start, 0),
+                                (index, c.len_utf8()),
+                                c,
+                            )));
+                        }
+                    }
+                }
+                '.' | '…' | '\n' => {
+                    opened = None;
+                    sentence_start = None;
+                }
+                // A question can start in the middle of a sentence (e.g., `Hola, ¿cómo estás?`)
+                ',' | ';' | ':' if opened.is_none() => {
+                    sentence_start = None;
+                }
+                c if c.is_alphanumeric() => {
+                    sentence_start.get_or_insert(index);
+                }
+                _ => {}
+            }
+        }
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::lint::{Fix, Rule};
+
+    use super::InvertedMarks;
+
+    #[test]
+    fn empty() {
+        assert!(InvertedMarks.check(br"").is_empty());
+    }
+
+    #[test]
+    fn correct() {
+        assert!(
+            InvertedMarks
+                .check("¿Qué pasa? ¡Hola amigo! Hola, ¿cómo estás?".as_bytes())
+                .is_empty()
+        );
+        assert!(
+            InvertedMarks
+                .check("¿Estás seguro?! No lo creo.".as_bytes())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let mut typos = InvertedMarks.check("Qué pasa? Hola, cómo estás?".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (17, 0).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (0, 0).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "add `¿` at the start of the sentence"
+        );
+        let Fix::Replace { replacement, .. } = typo.fix() else {
+            unreachable!()
+        };
+        assert_eq!(replacement, "¿");
+        assert!(typos.is_empty());
+
+        let mut typos = InvertedMarks.check("Muy bien! Gracias".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (0, 0).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "add `¡` at the start of the sentence"
+        );
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn mismatched() {
+        let mut typos = InvertedMarks.check("¿Qué dices! No lo sé".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (12, 1).into());
+        let Fix::Replace { replacement, .. } = typo.fix() else {
+            unreachable!()
+        };
+        assert_eq!(replacement, "?");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn not_sentences() {
+        assert!(
+            InvertedMarks
+                .check(br"abre https://ejemplo.com/?q=1 y x != y ahora")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,538 @@
+//! Typographical mistakes related to brackets and quotation marks that go in pairs.
+//!
+//! Here is a list of typos it can find:
+//! - [A bracket or a quotation mark without its counterpart](`TypoUnbalancedPair`)
+use std::ops::Range;
+
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::SharedSource;
+use super::{Rule, Typo, char_after, char_before, looks_like_prose};
+
+/// Opening and closing marks that must be balanced
+const PAIRS: &[(char, char)] = &[
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('“', '”'),
+    ('‘', '’'),
+    ('«', '»'),
+    ('‹', '›'),
+];
+
+/// Opening and closing marks that must be balanced in German
+///
+/// The English quotation marks are kept so they are balanced too, since another rule suggests
+/// to replace them with German ones.
+const GERMAN_PAIRS: &[(char, char)] = &[
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('„', '“'),
+    ('‚', '‘'),
+    ('»', '«'),
+    ('›', '‹'),
+    ('“', '”'),
+    ('‘', '’'),
+    ('«', '»'),
+    ('‹', '›'),
+];
+
+/// A bracket or a quotation mark has been opened but never closed, or closed but never opened.
+///
+/// It applies to parentheses, square brackets, curly brackets, straight quotation marks (`"` and `'`),
+/// curly quotation marks (`“”` and `‘’`), and guillemets (`«»` and `‹›`).
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `(see the docs`, should be `(see the docs)`
+/// - `expected 'foo`, should be `expected 'foo'`
+/// - `a value] is missing`, should be `[a value] is missing`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This bracket or quotation mark is not balanced")]
+#[diagnostic(code("typope::unbalanced-pair"), url(docsrs))]
+pub struct TypoUnbalancedPair {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label(primary, "Unmatched `{mark}`")]
+    span: SourceSpan,
+
+    #[label("Expected `{expected}` here")]
+    expected_span: SourceSpan,
+
+    mark: char,
+
+    expected: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoUnbalancedPair {
+    fn new(
+        span: impl Into<SourceSpan>,
+        mark: char,
+        expected_span: impl Into<SourceSpan>,
+        expected: char,
+    ) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            expected_span: expected_span.into(),
+            mark,
+            expected,
+            help: format!("add the missing `{expected}` or remove `{mark}`"),
+        }
+    }
+}
+
+impl Typo for TypoUnbalancedPair {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+        self.expected_span = lintable.source_span(self.expected_span);
+    }
+}
+
+/// An opening mark waiting for its closing counterpart
+struct Open {
+    mark: char,
+    closing: char,
+    index: usize,
+}
+
+impl Open {
+    fn unclosed(&self, expected_at: usize) -> Box<dyn Typo> {
+        Box::new(TypoUnbalancedPair::new(
+            (self.index, self.mark.len_utf8()),
+            self.mark,
+            (expected_at, 0),
+            self.closing,
+        ))
+    }
+}
+
+/// A rule that detects brackets and quotation marks that are not balanced.
+///
+/// Currently, it can only find and generate the following typo: [`TypoUnbalancedPair`].
+///
+/// To avoid false positives, it only checks strings that look like prose
+/// and it tolerates emoticons (e.g., `:)`), list markers (e.g., `1)` or `a)`),
+/// apostrophes (e.g., `don't` or `users'`), and marks quoted as code (e.g., ``expected `)` `` or `')'`).
+///
+/// When the locale is German, it also balances German quotation marks (`„“`, `‚‘`, `»«`, and `›‹`).
+pub struct Pairs {
+    pairs: &'static [(char, char)],
+}
+
+impl Pairs {
+    /// Creates the rule with the marks used by the language of the text
+    pub fn new(language: Option<&str>) -> Self {
+        let pairs = if language == Some("de") {
+            GERMAN_PAIRS
+        } else {
+            PAIRS
+        };
+
+        Self { pairs }
+    }
+
+    /// Whether the mark at this index is part of an emoticon (e.g., `:)`, `;-(`, or `:'(`)
+    fn is_emoticon(text: &str, index: usize) -> bool {
+        if !char_after(text, index).is_some_and(|c| "()[]".contains(c)) {
+            return false;
+        }
+
+        match char_before(text, index) {
+            Some(':' | ';' | '=') => true,
+            Some(c @ ('-' | '\'')) => {
+                matches!(char_before(text, index - c.len_utf8()), Some(':' | ';'))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the ranges of the text that quote code, whose marks do not need to be balanced:
+    /// code spans between backticks (e.g., `` `)` ``) and single characters between quotation marks (e.g., `')'`)
+    fn code_ranges(text: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut position = 0;
+        while let Some(start) = text
+            .get(position..)
+            .and_then(|rest| rest.find('`'))
+            .map(|index| position + index)
+        {
+            // A code span is closed by the same number of backticks that opened it
+            let rest = text.get(start..).unwrap_or_default();
+            let delimiter = rest
+                .get(..rest.len() - rest.trim_start_matches('`').len())
+                .unwrap_or_default();
+            let content = start + delimiter.len();
+            let Some(end) = text.get(content..).and_then(|rest| rest.find(delimiter)) else {
+                break;
+            };
+            let end = content + end + delimiter.len();
+            ranges.push(start..end);
+            position = end;
+        }
+
+        let chars = text.char_indices().collect::<Vec<_>>();
+        ranges.extend(chars.windows(3).filter_map(|window| {
+            let [(start, quote), (_, c), (end, closing)] = window else {
+                return None;
+            };
+            let is_quoted = matches!(quote, '\'' | '"') && quote == closing;
+            (is_quoted && !c.is_alphanumeric() && !c.is_whitespace()).then_some(*start..end + 1)
+        }));
+
+        ranges
+    }
+
+    /// Whether the closing mark at this index ends a list marker (e.g., `1)` or `iv)`)
+    fn is_list_marker(text: &str, index: usize) -> bool {
+        let Some(before) = text.get(..index) else {
+            return false;
+        };
+        let marker = before
+            .chars()
+            .rev()
+            .take_while(|c| c.is_alphanumeric())
+            .count();
+        let marker_start = before
+            .char_indices()
+            .rev()
+            .nth(marker.saturating_sub(1))
+            .map_or(0, |(i, _)| i);
+
+        (1..=3).contains(&marker)
+            && char_before(text, marker_start).is_none_or(char::is_whitespace)
+            && char_after(text, index + 1).is_none_or(char::is_whitespace)
+    }
+}
+
+impl Rule for Pairs {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        // A string might be cut around some code (e.g., a code span in Markdown),
+        // so its marks may be balanced outside of it. In such a case, it usually
+        // starts or ends with a space or with the mark that is balanced outside of it.
+        let cut_at_start = text.starts_with(char::is_whitespace);
+        let cut_at_end = text.ends_with(char::is_whitespace);
+
+        let code_ranges = Self::code_ranges(text);
+
+        let mut typos = Vec::new();
+        let mut stack: Vec<Open> = Vec::new();
+        for (index, c) in text.char_indices() {
+            if code_ranges.iter().any(|range| range.contains(&index)) {
+                continue;
+            }
+            let before = char_before(text, index);
+            let after = char_after(text, index + c.len_utf8());
+            // Straight quotation marks look the same when they open or close a quote,
+            // so they can only close the innermost mark to avoid cascading errors.
+            let is_innermost = |mark: char| stack.last().is_some_and(|open| open.mark == mark);
+
+            let opening_for = match c {
+                '"' if !is_innermost('"') => Some('"'),
+                '\'' => {
+                    let alphanumeric_before = before.is_some_and(char::is_alphanumeric);
+                    let alphanumeric_after = after.is_some_and(char::is_alphanumeric);
+                    if alphanumeric_before && alphanumeric_after {
+                        // An apostrophe (e.g., `don't`)
+                        continue;
+                    }
+                    if Self::is_emoticon(text, index + c.len_utf8()) {
+                        // A tear in an emoticon (e.g., `:'(`)
+                        continue;
+                    }
+                    if is_innermost('\'') && !before.is_some_and(char::is_whitespace) {
+                        None
+                    } else if !alphanumeric_before && after.is_some_and(|c| !c.is_whitespace()) {
+                        Some('\'')
+                    } else {
+                        // Most likely a possessive (e.g., `users'`)
+                        continue;
+                    }
+                }
+                // Some marks can both open and close a quote in German (e.g., `“` in `„…“` or `»` in `«…»`)
+                _ if stack.iter().any(|open| open.closing == c) => None,
+                _ => self
+                    .pairs
+                    .iter()
+                    .find(|(opening, _)| *opening == c)
+                    .map(|(_, closing)| *closing),
+            };
+            if let Some(closing) = opening_for {
+                if !Self::is_emoticon(text, index) {
+                    stack.push(Open {
+                        mark: c,
+                        closing,
+                        index,
+                    });
+                }
+                continue;
+            }
+
+            let Some(&(opening, _)) = self
+                .pairs
+                .iter()
+                .chain([('"', '"'), ('\'', '\'')].iter())
+                .find(|(_, closing)| *closing == c)
+            else {
+                continue;
+            };
+
+            if let Some(position) = stack.iter().rposition(|open| open.closing == c) {
+                typos.extend(stack.drain(position + 1..).map(|open| open.unclosed(index)));
+                stack.pop();
+                continue;
+            }
+
+            // `’` is also used as an apostrophe
+            if cut_at_start
+                || text
+                    .get(..index)
+                    .is_some_and(|before| before.trim().is_empty())
+                || c == '’'
+                || Self::is_emoticon(text, index)
+                || (c == ')' && Self::is_list_marker(text, index))
+            {
+                continue;
+            }
+
+            let expected_at = stack
+                .last()
+                .map_or(0, |open| open.index + open.mark.len_utf8());
+            typos.push(Box::new(TypoUnbalancedPair::new(
+                (index, c.len_utf8()),
+                c,
+                (expected_at, 0),
+                opening,
+            )));
+        }
+
+        if !cut_at_end {
+            typos.extend(
+                stack
+                    .iter()
+                    .filter(|open| {
+                        text.get(open.index + open.mark.len_utf8()..)
+                            .is_some_and(|rest| !rest.trim().is_empty())
+                    })
+                    .map(|open| open.unclosed(text.len())),
+            );
+        }
+        typos.sort_by_key(|typo| typo.span().offset());
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::{Diagnostic, LabeledSpan};
+
+    use crate::lint::Rule;
+
+    use super::Pairs;
+
+    #[test]
+    fn empty() {
+        assert!(Pairs::new(None).check(br"").is_empty());
+    }
+
+    #[test]
+    fn balanced() {
+        assert!(
+            Pairs::new(None)
+                .check(br"see the docs (or the README) for [more] details")
+                .is_empty()
+        );
+        assert!(
+            Pairs::new(None)
+                .check(br#"expected "foo" or 'bar'"#)
+                .is_empty()
+        );
+        assert!(
+            Pairs::new(None)
+                .check("« bonjour » and “hello” or ‘hi’".as_bytes())
+                .is_empty()
+        );
+        assert!(
+            Pairs::new(None)
+                .check(br"value of {name} is (not {set})")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn unclosed_parenthesis() {
+        let mut typos = Pairs::new(None).check(br"(see the docs");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (0, 1).into());
+        assert_eq!(
+            typo.labels().unwrap().collect::<Vec<_>>(),
+            [
+                LabeledSpan::new_primary_with_span(Some("Unmatched `(`".into()), (0, 1)),
+                LabeledSpan::new_with_span(Some("Expected `)` here".into()), (13, 0)),
+            ]
+        );
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn unclosed_quote() {
+        let mut typos = Pairs::new(None).check(br"expected 'foo");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        assert!(typos.is_empty());
+
+        let mut typos = Pairs::new(None).check(br#"expected "foo bar"#);
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn unopened_bracket() {
+        let mut typos = Pairs::new(None).check(br"the value] is (missing])");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (22, 1).into());
+        assert_eq!(
+            typo.labels().unwrap().nth(1).unwrap(),
+            LabeledSpan::new_with_span(Some("Expected `[` here".into()), (15, 0)),
+        );
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn mismatched() {
+        let mut typos = Pairs::new(None).check(br"see (the 'docs) here");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        assert_eq!(
+            typo.labels().unwrap().nth(1).unwrap(),
+            LabeledSpan::new_with_span(Some("Expected `'` here".into()), (14, 0)),
+        );
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn stray_quote() {
+        let mut typos = Pairs::new(None).check(br#"a stray " before (pat="v") and [this]"#);
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (8, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn german() {
+        let rule = Pairs::new(Some("de"));
+        assert!(
+            rule.check("Er sagt „Hallo“ und ‚tschüss‘ oder »Hallo« und “hi”".as_bytes())
+                .is_empty()
+        );
+
+        let mut typos = rule.check("Er sagt „Hallo und geht".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (8, 3).into());
+        assert!(typos.is_empty());
+
+        assert_eq!(
+            Pairs::new(None)
+                .check("Er sagt „Hallo“ heute".as_bytes())
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn apostrophes() {
+        assert!(
+            Pairs::new(None)
+                .check(br"don't do it, it's the users' files")
+                .is_empty()
+        );
+        assert!(Pairs::new(None).check("don’t do it".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn list_markers() {
+        assert!(
+            Pairs::new(None)
+                .check(br"1) first item 2) second item")
+                .is_empty()
+        );
+        assert!(
+            Pairs::new(None)
+                .check(br"either a) this or b) that")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn emoticons() {
+        assert!(
+            Pairs::new(None)
+                .check(br"it works :) or not :-( hmm ;)")
+                .is_empty()
+        );
+        assert!(Pairs::new(None).check(br"oh no :'( why").is_empty());
+    }
+
+    #[test]
+    fn quoted_code() {
+        assert!(
+            Pairs::new(None)
+                .check(br"Expected `)` here, not `[` or ``(``")
+                .is_empty()
+        );
+        assert!(
+            Pairs::new(None)
+                .check(br#"expected ')' or "[" after the name"#)
+                .is_empty()
+        );
+        assert_eq!(
+            Pairs::new(None)
+                .check(br"see `the code` (here")
+                .pop()
+                .unwrap()
+                .span(),
+            (15, 1).into()
+        );
+    }
+
+    #[test]
+    fn not_prose() {
+        assert!(Pairs::new(None).check(br"(").is_empty());
+        assert!(Pairs::new(None).check(br"[a-z]+(").is_empty());
+        assert!(Pairs::new(None).check(br"}").is_empty());
+    }
+
+    #[test]
+    fn cut_strings() {
+        assert!(Pairs::new(None).check(br"(see the ").is_empty());
+        assert!(Pairs::new(None).check(br" for more details)").is_empty());
+        assert!(Pairs::new(None).check(br") such as this one").is_empty());
+        assert!(Pairs::new(None).check(br"fn main() {").is_empty());
+    }
+}
@@ -9,11 +9,15 @@ use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 use winnow::ascii::{alphanumeric1, digit1};
-use winnow::combinator::{alt, delimited, not, preceded, repeat, repeat_till, terminated};
+use winnow::combinator::{
+    alt, delimited, eof, not, peek, preceded, repeat, repeat_till, terminated,
+};
 use winnow::error::InputError;
-use winnow::token::{none_of, one_of, take};
+use winnow::token::{any, none_of, one_of, take};
 use winnow::{LocatingSlice, Parser};
 
+use crate::lang::Lintable;
+
 use super::{Fix, SharedSource};
 use super::{Rule, Typo};
 
@@ -21,13 +25,16 @@ use super::{Rule, Typo};
 ///
 /// In English and German typography, one must not put a space before a colon (`:`),
 /// a semicolon (`;`), a question mark (`?`), an exclamation mark (`!`),
-/// or an interrobang (`‽`).
+/// an interrobang (`‽`), a comma (`,`), or a period (`.`).
 ///
 /// # Examples
 ///
 /// Here is a list of mistakes that trigger this rule:
 /// - `Oh no !`, should be `Oh no!`
 /// - `here is a list of things :`, should be `here is a list of things:`
+/// - `foo , bar`, should be `foo, bar`
+/// - `done .`, should be `done.`
+/// - `Oh no\t!` or `Oh no\u{A0}!`, should be `Oh no!`
 #[derive(Error, Debug, Diagnostic)]
 #[error("In English typography there is no space before a punctuation mark")]
 #[diagnostic(code("typope::space-before-punctuation-mark"), url(docsrs))]
@@ -57,9 +64,9 @@ impl Typo for TypoSpaceBeforePunctuationMarks {
         self.span
     }
 
-    fn with_source(&mut self, src: SharedSource, offset: usize) {
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
         self.src = Some(src);
-        self.span = (self.span.offset() + offset, self.span.len()).into();
+        self.span = lintable.source_span(self.span);
     }
 
     fn fix(&self) -> Fix {
@@ -70,16 +77,86 @@ impl Typo for TypoSpaceBeforePunctuationMarks {
 /// A rule that detects typographical mistakes related to punctuation.
 ///
 /// Currently, it can only find and generate the following typo: [`TypoSpaceBeforePunctuationMarks`].
-pub struct Punctuation;
+///
+/// Any run of whitespace on a single line is detected (e.g., several spaces, a tab, or a non-breaking space),
+/// and the whole run is reported so that it can be removed.
+///
+/// In French, a run made only of non-breaking spaces before a high punctuation mark
+/// (e.g., `Bonjour\u{A0}!`) is expected and not reported.
+pub struct Punctuation {
+    french: bool,
+}
+
+impl Punctuation {
+    /// Creates the rule for the language of the text
+    pub fn new(language: Option<&str>) -> Self {
+        Self {
+            french: language == Some("fr"),
+        }
+    }
+
+    /// Whether the whitespace is the non-breaking space French typography expects before this punctuation mark
+    fn is_expected_space(&self, bytes: &[u8], punctuation_mark: char, range: Range<usize>) -> bool {
+        if !self.french || !matches!(punctuation_mark, ':' | '!' | '?' | '‽' | '⸘') {
+            return false;
+        }
+
+        bytes
+            .get(range)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .is_some_and(|spaces| spaces.chars().all(|c| matches!(c, '\u{A0}' | '\u{202F}')))
+    }
+}
+
+impl Default for Punctuation {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
 
 impl Rule for Punctuation {
     #[allow(clippy::type_complexity)]
     fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        /// Parses a single UTF-8 encoded character
+        fn character<'s>(
+            input: &mut LocatingSlice<&'s [u8]>,
+        ) -> Result<char, InputError<LocatingSlice<&'s [u8]>>> {
+            let width = match peek(any).parse_next(input)? {
+                0x00..=0x7F => 1usize,
+                0xC0..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                _ => 4,
+            };
+
+            take(width)
+                .verify_map(|bytes| std::str::from_utf8(bytes).ok()?.chars().next())
+                .parse_next(input)
+        }
+
+        /// Parses a run of whitespace that does not span multiple lines (e.g., spaces, tabs, or non-breaking spaces)
+        fn whitespace<'s>(
+            input: &mut LocatingSlice<&'s [u8]>,
+        ) -> Result<Range<usize>, InputError<LocatingSlice<&'s [u8]>>> {
+            repeat::<_, _, (), _, _>(
+                1..,
+                character.verify(|c: &char| c.is_whitespace() && !matches!(c, '\n' | '\r')),
+            )
+            .span()
+            .parse_next(input)
+        }
+
+        /// Parses a character that is neither a whitespace nor one of the excluded characters
+        fn word_end<'s>(
+            excluded: &'static [char],
+        ) -> impl Parser<LocatingSlice<&'s [u8]>, char, InputError<LocatingSlice<&'s [u8]>>>
+        {
+            character.verify(move |c: &char| !c.is_whitespace() && !excluded.contains(c))
+        }
+
         fn space_before_colon<'s>(
             input: &mut LocatingSlice<&'s [u8]>,
         ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
-            let (_space, range) =
-                delimited(none_of([' ', '>']), ' '.with_span(), ':').parse_next(input)?;
+            let range = delimited(word_end(&['>']), whitespace, ':').parse_next(input)?;
 
             // Handles cases when we have an emoji like `:fire:` or `:)`.
             // In such cases, we should not mark them as a typo.
@@ -91,9 +168,8 @@ impl Rule for Punctuation {
         fn space_before_exclamation_mark<'s>(
             input: &mut LocatingSlice<&'s [u8]>,
         ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
-            let (_space, range) =
-                delimited(none_of([' ', '&', '=', '>', '|']), ' '.with_span(), '!')
-                    .parse_next(input)?;
+            let range =
+                delimited(word_end(&['&', '=', '>', '|']), whitespace, '!').parse_next(input)?;
 
             // Do not mark such a string `x != y` as a typo
             not('=').parse_next(input)?;
@@ -121,8 +197,7 @@ impl Rule for Punctuation {
         fn space_before_question_mark<'s>(
             input: &mut LocatingSlice<&'s [u8]>,
         ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
-            let (_space, range) =
-                delimited(none_of([' ']), ' '.with_span(), '?').parse_next(input)?;
+            let range = delimited(word_end(&[]), whitespace, '?').parse_next(input)?;
 
             // Do not mark strings like ` ?Sized` as a typo: it has a meaning in Rust
             not("Sized").parse_next(input)?;
@@ -135,10 +210,37 @@ impl Rule for Punctuation {
             Ok(('?', range))
         }
 
+        fn space_before_comma<'s>(
+            input: &mut LocatingSlice<&'s [u8]>,
+        ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
+            let range = delimited(word_end(&[]), whitespace, ',').parse_next(input)?;
+
+            Ok((',', range))
+        }
+
+        fn space_before_period<'s>(
+            input: &mut LocatingSlice<&'s [u8]>,
+        ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
+            let range = delimited(word_end(&[]), whitespace, '.').parse_next(input)?;
+
+            // Only a period that ends a sentence is a typo, we do not want to mark
+            // file extensions (e.g., ` .rs`), paths (e.g., ` ./configure`),
+            // numbers (e.g., ` .5`), an ellipsis (e.g., ` ...`), or some kind of
+            // operator (e.g., `(block . (statement))` in a tree-sitter query) as typos.
+            peek(alt((
+                eof.void(),
+                one_of(['\n', '\r']).void(),
+                preceded(' ', one_of(|c: u8| c.is_ascii_uppercase())).void(),
+            )))
+            .parse_next(input)?;
+
+            Ok(('.', range))
+        }
+
         fn space_before_char<'s, const C: char>(
             input: &mut LocatingSlice<&'s [u8]>,
         ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
-            let (_space, range) = terminated(' '.with_span(), C).parse_next(input)?;
+            let range = delimited(word_end(&[]), whitespace, C).parse_next(input)?;
 
             Ok((C, range))
         }
@@ -150,6 +252,8 @@ impl Rule for Punctuation {
                 space_before_colon,
                 space_before_exclamation_mark,
                 space_before_question_mark,
+                space_before_comma,
+                space_before_period,
                 space_before_char::<'‽'>,
                 space_before_char::<'⸘'>,
             ))
@@ -158,49 +262,57 @@ impl Rule for Punctuation {
 
         fn locate_space_before_punctuation<'s>(
             input: &mut LocatingSlice<&'s [u8]>,
-        ) -> Result<Box<dyn Typo>, InputError<LocatingSlice<&'s [u8]>>> {
-            let (_, (punctuation_mark, range)): (Vec<u8>, (char, Range<usize>)) = repeat_till(
+        ) -> Result<(char, Range<usize>), InputError<LocatingSlice<&'s [u8]>>> {
+            let (_, space): (Vec<u8>, (char, Range<usize>)) = repeat_till(
                 1..,
                 take::<_, _, InputError<_>>(1usize),
                 space_before_punctuation,
             )
             .parse_next(input)?;
 
-            // We only mark the space that is invalid not the rest
-            let span = (range.start, 1);
-
-            Ok(Box::new(TypoSpaceBeforePunctuationMarks::new(
-                span,
-                punctuation_mark,
-            )))
+            Ok(space)
         }
 
-        repeat(0.., locate_space_before_punctuation)
+        let spaces: Vec<(char, Range<usize>)> = repeat(0.., locate_space_before_punctuation)
             .parse_next(&mut LocatingSlice::new(bytes))
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        spaces
+            .into_iter()
+            .filter(|(punctuation_mark, range)| {
+                !self.is_expected_space(bytes, *punctuation_mark, range.clone())
+            })
+            .map(|(punctuation_mark, range)| {
+                // We only mark the whitespace that is invalid not the rest
+                let span = (range.start, range.len());
+
+                Box::new(TypoSpaceBeforePunctuationMarks::new(span, punctuation_mark)) as _
+            })
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::lint::{Rule, SharedSource};
+    use crate::lang::LintableString;
+    use crate::lint::{Fix, Rule, SharedSource};
 
     use super::Punctuation;
 
     #[test]
     fn empty() {
-        assert!(Punctuation.check(br"").is_empty());
+        assert!(Punctuation::default().check(br"").is_empty());
     }
 
     #[test]
     fn space_after_colon() {
-        let typos = Punctuation.check(br"test: foobar");
+        let typos = Punctuation::default().check(br"test: foobar");
         assert!(typos.is_empty());
     }
 
     #[test]
     fn typo_colon() {
-        let mut typos = Punctuation.check(br"test : foobar");
+        let mut typos = Punctuation::default().check(br"test : foobar");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (4, 1).into());
         assert!(typos.is_empty());
@@ -208,7 +320,7 @@ mod tests {
 
     #[test]
     fn typo_question_mark() {
-        let mut typos = Punctuation.check(br"footest ? foobar ?fooooo");
+        let mut typos = Punctuation::default().check(br"footest ? foobar ?fooooo");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (16, 1).into());
         let typo = typos.pop().unwrap();
@@ -218,7 +330,7 @@ mod tests {
 
     #[test]
     fn typo_exclamation_mark() {
-        let mut typos = Punctuation.check(br"footest ! barfoobar");
+        let mut typos = Punctuation::default().check(br"footest ! barfoobar");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (7, 1).into());
         assert!(typos.is_empty());
@@ -226,7 +338,7 @@ mod tests {
 
     #[test]
     fn typo_exclamation_mark_repeated() {
-        let mut typos = Punctuation.check(br"footest !!!! barfoobar");
+        let mut typos = Punctuation::default().check(br"footest !!!! barfoobar");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (7, 1).into());
         assert!(typos.is_empty());
@@ -234,22 +346,22 @@ mod tests {
 
     #[test]
     fn typo_neq() {
-        assert!(Punctuation.check(br"maybe 0 != 1?").is_empty());
+        assert!(Punctuation::default().check(br"maybe 0 != 1?").is_empty());
     }
 
     #[test]
     fn typo_before_end_of_line() {
-        let mut typos = Punctuation.check(br"footest !");
+        let mut typos = Punctuation::default().check(br"footest !");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (7, 1).into());
         assert!(typos.is_empty());
 
-        let mut typos = Punctuation.check(br"footest ?");
+        let mut typos = Punctuation::default().check(br"footest ?");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (7, 1).into());
         assert!(typos.is_empty());
 
-        let mut typos = Punctuation.check(br"footest :");
+        let mut typos = Punctuation::default().check(br"footest :");
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (7, 1).into());
         assert!(typos.is_empty());
@@ -257,7 +369,7 @@ mod tests {
 
     #[test]
     fn multiple_typos() {
-        let mut typos = Punctuation.check(br"footest ! barfoobar : oh no ?");
+        let mut typos = Punctuation::default().check(br"footest ! barfoobar : oh no ?");
 
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (27, 1).into());
@@ -271,51 +383,109 @@ mod tests {
 
     #[test]
     fn typo_colon_multiple_spaces() {
-        let typos = Punctuation.check(br"test     : foobar");
+        let mut typos = Punctuation::default().check(br"test     : foobar");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (4, 5).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn typo_unicode_whitespace() {
+        let mut typos =
+            Punctuation::default().check("footest\t! bar\u{A0}? baz \u{202F}\t:".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (20, 5).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (13, 2).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 1).into());
+        assert!(matches!(typo.fix(), Fix::Remove { span } if span == (7, 1).into()));
         assert!(typos.is_empty());
     }
 
+    #[test]
+    fn non_breaking_space_in_french() {
+        let text = "Bonjour\u{A0}! Ça va\u{202F}? Voici\u{A0}: rien\u{A0}, fin";
+        assert_eq!(Punctuation::new(Some("en")).check(text.as_bytes()).len(), 4);
+        assert_eq!(Punctuation::new(Some("es")).check(text.as_bytes()).len(), 4);
+
+        let mut typos = Punctuation::new(Some("de")).check("Achtung\u{A0}!".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 2).into());
+        assert!(typos.is_empty());
+
+        let mut typos = Punctuation::new(Some("fr")).check(text.as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (text.find("\u{A0},").unwrap(), 2).into());
+        assert!(typos.is_empty());
+
+        let mut typos = Punctuation::new(Some("fr")).check(b"Bonjour !");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn whitespace_on_another_line() {
+        assert!(Punctuation::default().check(b"a && b\n    !c").is_empty());
+        assert!(
+            Punctuation::default()
+                .check(b"some text\n, more text")
+                .is_empty()
+        );
+        assert!(
+            Punctuation::default()
+                .check("voilà :)".as_bytes())
+                .is_empty()
+        );
+    }
+
     #[test]
     fn typo_rust_sized() {
-        let typos = Punctuation.check(br"test: ?Sized foobar");
+        let typos = Punctuation::default().check(br"test: ?Sized foobar");
         assert!(typos.is_empty());
     }
 
     #[test]
     fn emoji() {
-        assert!(Punctuation.check(br":waving_hand:").is_empty());
-        assert!(Punctuation.check(br"footest :fire: bar").is_empty());
-        assert!(Punctuation.check(br"foobar :)").is_empty());
-        assert!(Punctuation.check(br":D").is_empty());
-        assert!(Punctuation.check(br" :> ").is_empty());
-        assert!(Punctuation.check(br"foo :'( bar").is_empty());
+        assert!(Punctuation::default().check(br":waving_hand:").is_empty());
+        assert!(
+            Punctuation::default()
+                .check(br"footest :fire: bar")
+                .is_empty()
+        );
+        assert!(Punctuation::default().check(br"foobar :)").is_empty());
+        assert!(Punctuation::default().check(br":D").is_empty());
+        assert!(Punctuation::default().check(br" :> ").is_empty());
+        assert!(Punctuation::default().check(br"foo :'( bar").is_empty());
     }
 
     #[test]
     fn typo_source() {
         let source = r#""test : foobar""#;
-        let mut typos = Punctuation.check(source.trim_matches('"').as_bytes());
+        let string = source.trim_matches('"');
+        let mut typos = Punctuation::default().check(string.as_bytes());
         let mut typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (4, 1).into());
         let source = SharedSource::new("fake.rs", source.to_owned().into_bytes());
-        typo.with_source(source, 1);
+        typo.with_source(source, &LintableString::new(1, string).into());
         assert_eq!(typo.span(), (5, 1).into());
         assert!(typos.is_empty());
     }
 
     #[test]
     fn interrobang() {
-        assert!(Punctuation.check(r"test‽".as_bytes()).is_empty());
-        assert!(Punctuation.check(br"test?!").is_empty());
-        assert!(Punctuation.check(br"test!?").is_empty());
-        assert!(Punctuation.check(r"test⸘".as_bytes()).is_empty());
+        assert!(Punctuation::default().check(r"test‽".as_bytes()).is_empty());
+        assert!(Punctuation::default().check(br"test?!").is_empty());
+        assert!(Punctuation::default().check(br"test!?").is_empty());
+        assert!(Punctuation::default().check(r"test⸘".as_bytes()).is_empty());
 
-        let mut typos = Punctuation.check(r"test ‽".as_bytes());
+        let mut typos = Punctuation::default().check(r"test ‽".as_bytes());
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (4, 1).into());
         assert!(typos.is_empty());
 
-        let mut typos = Punctuation.check(r"test ?! abc ⸘".as_bytes());
+        let mut typos = Punctuation::default().check(r"test ?! abc ⸘".as_bytes());
         let typo = typos.pop().unwrap();
         assert_eq!(typo.span(), (11, 1).into());
         let typo = typos.pop().unwrap();
@@ -326,7 +496,7 @@ mod tests {
     #[test]
     fn looks_like_shell() {
         assert!(
-            Punctuation
+            Punctuation::default()
                 .check(br"[ ! -e /run/dbus ] || mount -t tmpfs none /run/dbus")
                 .is_empty()
         );
@@ -335,7 +505,7 @@ mod tests {
     #[test]
     fn looks_like_c_macro_generated() {
         assert!(
-            Punctuation
+            Punctuation::default()
                 .check(br"#  elif !defined(missing_arch_template)")
                 .is_empty()
         );
@@ -344,7 +514,7 @@ mod tests {
     #[test]
     fn looks_like_url_parameter() {
         assert!(
-            Punctuation
+            Punctuation::default()
                 .check(br"Add ?var=1&var2=44 to the URL")
                 .is_empty()
         );
@@ -353,7 +523,7 @@ mod tests {
     #[test]
     fn sqlite_prepared_statement() {
         assert!(
-            Punctuation
+            Punctuation::default()
                 .check(br"SELECT a FROM b WHERE c = ?1 AND d = ?2")
                 .is_empty()
         );
@@ -361,13 +531,55 @@ mod tests {
 
     #[test]
     fn fn_return() {
-        assert!(Punctuation.check(br"fn() -> !").is_empty());
+        assert!(Punctuation::default().check(br"fn() -> !").is_empty());
+    }
+
+    #[test]
+    fn typo_comma() {
+        let mut typos = Punctuation::default().check(br"foo , bar ,baz");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (3, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn typo_period() {
+        let mut typos = Punctuation::default().check(br"done . Next step is done .");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (24, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (4, 1).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn period_not_ending_a_sentence() {
+        assert!(
+            Punctuation::default()
+                .check(br"files ending with .rs")
+                .is_empty()
+        );
+        assert!(
+            Punctuation::default()
+                .check(br"run ./configure first")
+                .is_empty()
+        );
+        assert!(Punctuation::default().check(br"a ratio of .5").is_empty());
+        assert!(Punctuation::default().check(br"and so on ...").is_empty());
+        assert!(Punctuation::default().check(br"wait ... what").is_empty());
+        assert!(
+            Punctuation::default()
+                .check(br"(block . (expression_statement))")
+                .is_empty()
+        );
     }
 
     #[test]
     fn condition() {
         assert!(
-            Punctuation
+            Punctuation::default()
                 .check(br"a & !b & !c | !z  or !(y | w)")
                 .is_empty()
         );
@@ -0,0 +1,300 @@
+//! Typographical mistakes related to mathematical symbols written with ASCII characters.
+//!
+//! Here is a list of typos it can find:
+//! - [A letter `x` used as a multiplication sign](`TypoMultiplicationSign`)
+//! - [A quotation mark used as a prime](`TypoPrime`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, char_after, char_before, looks_like_prose};
+
+/// The letter `x` has been used instead of the multiplication sign (`×`).
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `1920x1080`, should be `1920×1080`
+/// - `2 x 3`, should be `2 × 3`
+#[derive(Error, Debug, Diagnostic)]
+#[error("The letter `x` is used instead of the multiplication sign")]
+#[diagnostic(code("typope::multiplication-sign"), url(docsrs))]
+pub struct TypoMultiplicationSign {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Expected `×` here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+}
+
+impl TypoMultiplicationSign {
+    fn new(span: impl Into<SourceSpan>, letter: char) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("replace `{letter}` with `×`"),
+        }
+    }
+}
+
+impl Typo for TypoMultiplicationSign {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: "×".into(),
+        }
+    }
+}
+
+/// A straight quotation mark has been used instead of a prime for feet, inches, minutes, or seconds.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `6'2"`, should be `6′2″`
+/// - `45° 30' 15"`, should be `45° 30′ 15″`
+#[derive(Error, Debug, Diagnostic)]
+#[error("A quotation mark is used instead of a prime")]
+#[diagnostic(code("typope::prime"), url(docsrs))]
+pub struct TypoPrime {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Expected `{prime}` here")]
+    span: SourceSpan,
+
+    prime: char,
+
+    #[help]
+    help: String,
+}
+
+impl TypoPrime {
+    fn new(span: impl Into<SourceSpan>, mark: char) -> Self {
+        let prime = if mark == '"' { '″' } else { '′' };
+
+        Self {
+            src: None,
+            span: span.into(),
+            prime,
+            help: format!("replace `{mark}` with `{prime}`"),
+        }
+    }
+}
+
+impl Typo for TypoPrime {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.prime.into(),
+        }
+    }
+}
+
+/// A rule that detects mathematical symbols written with ASCII characters.
+///
+/// It can find and generate the following typos:
+/// - [`TypoMultiplicationSign`]
+/// - [`TypoPrime`]
+///
+/// It is pedantic, so it only applies when enabled in the configuration:
+///
+/// ```toml
+/// [default]
+/// pedantic = true
+/// ```
+///
+/// To avoid false positives, it only checks strings that look like prose, and it ignores
+/// hexadecimal numbers (e.g., `0x1F`), identifiers (e.g., `h264x2`), and quoted numbers (e.g., `"10"`).
+pub struct Symbols;
+
+impl Symbols {
+    /// Whether a number can start at this index without being part of a word (e.g., `h264`)
+    fn starts_number(text: &str, index: usize) -> bool {
+        char_before(text, index).is_none_or(|c| !c.is_alphanumeric() && !"_.".contains(c))
+    }
+
+    /// Returns the end of the number that starts at this index
+    fn number_at(text: &str, index: usize) -> Option<usize> {
+        let rest = text.get(index..)?;
+        let mut end = index;
+        for (i, c) in rest.char_indices() {
+            let is_decimal_separator =
+                c == '.' && i > 0 && char_after(rest, i + 1).is_some_and(|c| c.is_ascii_digit());
+            if !c.is_ascii_digit() && !is_decimal_separator {
+                break;
+            }
+            end = index + i + 1;
+        }
+
+        (end > index).then_some(end)
+    }
+
+    /// Finds `x` between two numbers (e.g., `1920x1080` or `2 x 3`)
+    fn multiplication(text: &str, start: usize, end: usize) -> Option<Box<dyn Typo>> {
+        let spaced = char_after(text, end) == Some(' ');
+        let letter_index = end + usize::from(spaced);
+        let letter = char_after(text, letter_index).filter(|c| matches!(c, 'x' | 'X'))?;
+        let after_letter = letter_index + 1;
+        if spaced != (char_after(text, after_letter) == Some(' ')) {
+            return None;
+        }
+
+        // Hexadecimal numbers (e.g., `0x1F`)
+        if !spaced && text.get(start..end) == Some("0") {
+            return None;
+        }
+
+        let other_start = after_letter + usize::from(spaced);
+        let other_end = Self::number_at(text, other_start)?;
+        if char_after(text, other_end).is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+
+        Some(Box::new(TypoMultiplicationSign::new(
+            (letter_index, 1),
+            letter,
+        )))
+    }
+
+    /// Finds `'` or `"` right after a number (e.g., `6'2"`)
+    fn prime(text: &str, end: usize) -> Option<Box<dyn Typo>> {
+        let mark = char_after(text, end).filter(|c| matches!(c, '\'' | '"'))?;
+        if char_after(text, end + 1).is_some_and(|c| c.is_alphabetic() || c == mark) {
+            return None;
+        }
+
+        // The mark closes a quote (e.g., `"10"`), so another mark that is not a prime opens it
+        let before = text.get(..end)?;
+        let opening_marks = before
+            .char_indices()
+            .filter(|(i, c)| {
+                *c == mark && !char_before(before, *i).is_some_and(|c| c.is_ascii_digit())
+            })
+            .count();
+        if opening_marks % 2 == 1 {
+            return None;
+        }
+
+        Some(Box::new(TypoPrime::new((end, 1), mark)))
+    }
+}
+
+impl Rule for Symbols {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        text.char_indices()
+            .filter(|(index, c)| c.is_ascii_digit() && Self::starts_number(text, *index))
+            .filter_map(|(index, _)| Some((index, Self::number_at(text, index)?)))
+            .flat_map(|(start, end)| {
+                Self::multiplication(text, start, end)
+                    .into_iter()
+                    .chain(Self::prime(text, end))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::lint::{Fix, Rule};
+
+    use super::Symbols;
+
+    fn replacement(fix: Fix) -> Option<String> {
+        let Fix::Replace { replacement, .. } = fix else {
+            return None;
+        };
+
+        Some(replacement)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Symbols.check(br"").is_empty());
+    }
+
+    #[test]
+    fn correct() {
+        assert!(
+            Symbols
+                .check("a resolution of 1920×1080 or 2 × 3 tiles".as_bytes())
+                .is_empty()
+        );
+        assert!(Symbols.check("he is 6′2″ tall".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn multiplication_sign() {
+        let mut typos = Symbols.check(br"a resolution of 1920x1080 or 2 x 3 tiles");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (31, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (20, 1).into());
+        assert_eq!(typo.help().unwrap().to_string(), "replace `x` with `×`");
+        assert_eq!(replacement(typo.fix()).unwrap(), "×");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn primes() {
+        let mut typos = Symbols.check(br#"he is 6'2" tall at 45 30' 15" north"#);
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (28, 1).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "″");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (24, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (7, 1).into());
+        assert_eq!(typo.help().unwrap().to_string(), "replace `'` with `′`");
+        assert_eq!(replacement(typo.fix()).unwrap(), "′");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn ignored() {
+        assert!(Symbols.check(br"the value 0x1F or 0X10 is set").is_empty());
+        assert!(
+            Symbols
+                .check(br"encoded with h264x2 or 2x3a here")
+                .is_empty()
+        );
+        assert!(Symbols.check(br"it is 3x faster than 2 x y").is_empty());
+        assert!(Symbols.check(br#"the value is "10" or '5' now"#).is_empty());
+        assert!(Symbols.check(br"back in the 90's it was fine").is_empty());
+    }
+}
@@ -0,0 +1,330 @@
+//! Typographical mistakes related to invisible or confusable Unicode characters.
+//!
+//! Here is a list of typos it can find:
+//! - [An invisible character](`TypoInvisibleCharacter`)
+//! - [A character that looks like a Latin letter](`TypoConfusableCharacter`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo};
+
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
+
+/// Characters that are not visible when reviewing text, with their Unicode name
+const INVISIBLE: &[(char, &str)] = &[
+    ('\u{180E}', "MONGOLIAN VOWEL SEPARATOR"),
+    ('\u{200B}', "ZERO WIDTH SPACE"),
+    ('\u{2060}', "WORD JOINER"),
+    (BYTE_ORDER_MARK, "ZERO WIDTH NO-BREAK SPACE"),
+    // Bidirectional control characters that can change how text is displayed (also known as Trojan Source)
+    ('\u{202A}', "LEFT-TO-RIGHT EMBEDDING"),
+    ('\u{202B}', "RIGHT-TO-LEFT EMBEDDING"),
+    ('\u{202C}', "POP DIRECTIONAL FORMATTING"),
+    ('\u{202D}', "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202E}', "RIGHT-TO-LEFT OVERRIDE"),
+    ('\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', "FIRST STRONG ISOLATE"),
+    ('\u{2069}', "POP DIRECTIONAL ISOLATE"),
+];
+
+/// Cyrillic letters that look like Latin letters, with their Unicode name and the Latin letter they look like
+const CONFUSABLES: &[(char, &str, char)] = &[
+    ('а', "CYRILLIC SMALL LETTER A", 'a'),
+    ('в', "CYRILLIC SMALL LETTER VE", 'b'),
+    ('е', "CYRILLIC SMALL LETTER IE", 'e'),
+    ('і', "CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I", 'i'),
+    ('ј', "CYRILLIC SMALL LETTER JE", 'j'),
+    ('о', "CYRILLIC SMALL LETTER O", 'o'),
+    ('р', "CYRILLIC SMALL LETTER ER", 'p'),
+    ('с', "CYRILLIC SMALL LETTER ES", 'c'),
+    ('ѕ', "CYRILLIC SMALL LETTER DZE", 's'),
+    ('у', "CYRILLIC SMALL LETTER U", 'y'),
+    ('х', "CYRILLIC SMALL LETTER HA", 'x'),
+    ('ԁ', "CYRILLIC SMALL LETTER KOMI DE", 'd'),
+    ('А', "CYRILLIC CAPITAL LETTER A", 'A'),
+    ('В', "CYRILLIC CAPITAL LETTER VE", 'B'),
+    ('Е', "CYRILLIC CAPITAL LETTER IE", 'E'),
+    ('І', "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I", 'I'),
+    ('Ј', "CYRILLIC CAPITAL LETTER JE", 'J'),
+    ('К', "CYRILLIC CAPITAL LETTER KA", 'K'),
+    ('М', "CYRILLIC CAPITAL LETTER EM", 'M'),
+    ('Н', "CYRILLIC CAPITAL LETTER EN", 'H'),
+    ('О', "CYRILLIC CAPITAL LETTER O", 'O'),
+    ('Р', "CYRILLIC CAPITAL LETTER ER", 'P'),
+    ('С', "CYRILLIC CAPITAL LETTER ES", 'C'),
+    ('Ѕ', "CYRILLIC CAPITAL LETTER DZE", 'S'),
+    ('Т', "CYRILLIC CAPITAL LETTER TE", 'T'),
+    ('Х', "CYRILLIC CAPITAL LETTER HA", 'X'),
+];
+
+/// An invisible character has been detected.
+///
+/// Such characters (e.g., a zero width space, a byte order mark in the middle of a text,
+/// or a bidirectional control character) cannot be seen when reviewing a change,
+/// but they can change how a text is displayed or processed.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `foo\u{200B}bar`, should be `foobar`
+/// - `access\u{202E}level`, should be `accesslevel`
+#[derive(Error, Debug, Diagnostic)]
+#[error("An invisible character has been found")]
+#[diagnostic(code("typope::invisible-character"), url(docsrs))]
+pub struct TypoInvisibleCharacter {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Invisible character here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+}
+
+impl TypoInvisibleCharacter {
+    fn new(span: impl Into<SourceSpan>, character: char, name: &str) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!("remove U+{:04X} {name}", u32::from(character)),
+        }
+    }
+}
+
+impl Typo for TypoInvisibleCharacter {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Remove { span: self.span }
+    }
+}
+
+/// A character that looks like a Latin letter has been detected in a word written with Latin letters.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `pаssword` (with `а` being U+0430 CYRILLIC SMALL LETTER A), should be `password`
+#[derive(Error, Debug, Diagnostic)]
+#[error("This character looks like a Latin letter, but it is not one")]
+#[diagnostic(code("typope::confusable-character"), url(docsrs))]
+pub struct TypoConfusableCharacter {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Confusable character here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: char,
+}
+
+impl TypoConfusableCharacter {
+    fn new(span: impl Into<SourceSpan>, character: char, name: &str, replacement: char) -> Self {
+        Self {
+            src: None,
+            span: span.into(),
+            help: format!(
+                "replace U+{:04X} {name} with `{replacement}`",
+                u32::from(character)
+            ),
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoConfusableCharacter {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        Fix::Replace {
+            span: self.span,
+            replacement: self.replacement.into(),
+        }
+    }
+}
+
+/// A rule that detects invisible or confusable Unicode characters.
+///
+/// It can find and generate the following typos:
+/// - [`TypoInvisibleCharacter`]
+/// - [`TypoConfusableCharacter`]
+///
+/// Unlike other rules, it also checks comments.
+pub struct Unicode;
+
+impl Unicode {
+    fn invisible_characters(text: &str) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.char_indices().filter_map(|(index, c)| {
+            let (_, name) = INVISIBLE.iter().find(|(invisible, _)| *invisible == c)?;
+
+            Some(Box::new(TypoInvisibleCharacter::new((index, c.len_utf8()), c, name)) as _)
+        })
+    }
+
+    fn confusable_characters(text: &str) -> impl Iterator<Item = Box<dyn Typo>> + '_ {
+        text.char_indices().filter_map(|(index, c)| {
+            let (_, name, replacement) = CONFUSABLES
+                .iter()
+                .find(|(confusable, _, _)| *confusable == c)?;
+
+            // Only a word that mixes Latin letters with confusable ones is a typo
+            let before = text.get(..index)?.chars().rev();
+            let after = text.get(index + c.len_utf8()..)?.chars();
+            let latin_word = before
+                .take_while(|c| c.is_alphabetic())
+                .chain(after.take_while(|c| c.is_alphabetic()))
+                .any(|c| c.is_ascii_alphabetic());
+            if !latin_word {
+                return None;
+            }
+
+            Some(Box::new(TypoConfusableCharacter::new(
+                (index, c.len_utf8()),
+                c,
+                name,
+                *replacement,
+            )) as _)
+        })
+    }
+}
+
+impl Rule for Unicode {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+
+        let mut typos = Self::invisible_characters(text)
+            .chain(Self::confusable_characters(text))
+            .collect::<Vec<_>>();
+        typos.sort_by_key(|typo| typo.span().offset());
+
+        typos
+    }
+
+    fn check_lintable(&self, lintable: &Lintable) -> Vec<Box<dyn Typo>> {
+        let mut typos = self.check(lintable.as_str().as_bytes());
+
+        // A byte order mark is expected at the start of a file
+        if lintable.offset() == 0 && lintable.as_str().starts_with(BYTE_ORDER_MARK) {
+            typos.retain(|typo| typo.span().offset() != 0);
+        }
+
+        typos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::lang::{Kind, Lintable, LintableString};
+    use crate::lint::{Fix, Rule};
+
+    use super::Unicode;
+
+    #[test]
+    fn empty() {
+        assert!(Unicode.check(br"").is_empty());
+    }
+
+    #[test]
+    fn zero_width_space() {
+        let mut typos = Unicode.check("foo\u{200B}bar".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (3, 3).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "remove U+200B ZERO WIDTH SPACE"
+        );
+        assert!(matches!(typo.fix(), Fix::Remove { .. }));
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn bidi_override() {
+        let mut typos = Unicode.check("if access\u{202E} {\u{2066}admin".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (14, 3).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "remove U+2066 LEFT-TO-RIGHT ISOLATE"
+        );
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 3).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let lintable =
+            |offset, value: &str| Lintable::new(LintableString::new(offset, value), Kind::Text);
+
+        assert!(
+            Unicode
+                .check_lintable(&lintable(0, "\u{FEFF}hello"))
+                .is_empty()
+        );
+        assert_eq!(
+            Unicode.check_lintable(&lintable(10, "\u{FEFF}hello")).len(),
+            1
+        );
+        assert_eq!(
+            Unicode
+                .check_lintable(&lintable(0, "hello\u{FEFF}world"))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn confusable() {
+        let mut typos = Unicode.check("your pаssword".as_bytes());
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (6, 2).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "replace U+0430 CYRILLIC SMALL LETTER A with `a`"
+        );
+        let Fix::Replace { replacement, .. } = typo.fix() else {
+            unreachable!()
+        };
+        assert_eq!(replacement, "a");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn cyrillic_words() {
+        assert!(Unicode.check("Привет, hello".as_bytes()).is_empty());
+        assert!(Unicode.check("Ахмед".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn comments() {
+        let comment = Lintable::new(LintableString::new(0, "foo\u{200B}bar"), Kind::Comment);
+        assert_eq!(Unicode.check_lintable(&comment).len(), 1);
+    }
+}
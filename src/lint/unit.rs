@@ -0,0 +1,347 @@
+//! Typographical mistakes related to the spacing between a number and its unit.
+//!
+//! Here is a list of typos it can find:
+//! - [A missing, extra, or wrong space between a number and its unit](`TypoUnitSpacing`)
+use miette::{Diagnostic, SourceSpan};
+
+use thiserror::Error;
+
+use crate::config::{UnitConvention, UnitSpace};
+use crate::lang::Lintable;
+
+use super::{Fix, SharedSource};
+use super::{Rule, Typo, char_after, char_before, looks_like_prose};
+
+const NO_BREAK_SPACE: char = '\u{A0}';
+const NARROW_NO_BREAK_SPACE: char = '\u{202F}';
+
+/// Units that must be separated from a number by a space.
+///
+/// Single-letter units (e.g., `s` or `m`) are not part of the list since they are
+/// often used in a compact notation (e.g., a duration like `30s` in a command line).
+const UNITS: &[&str] = &[
+    "mg", "kg", "µm", "nm", "mm", "cm", "km", "ns", "µs", "ms", "min", "Hz", "kHz", "MHz", "GHz",
+    "kB", "KB", "MB", "GB", "TB", "PB", "KiB", "MiB", "GiB", "TiB", "PiB", "kb", "Kb", "Mb", "Gb",
+    "bps", "kbps", "Mbps", "Gbps", "mA", "mV", "kV", "mW", "kW", "MW", "GW", "Wh", "kWh", "kJ",
+    "mL", "ml", "kPa", "hPa", "dB", "°C", "°F",
+];
+
+/// Languages that follow the SI convention by default, with a space before a percent sign (e.g., `5 %`)
+const SI_LANGUAGES: &[&str] = &[
+    "ca", "cs", "da", "de", "es", "fi", "fr", "nb", "nn", "no", "ru", "sk", "sv", "uk",
+];
+
+/// The spacing between a number and its unit does not follow the expected convention.
+///
+/// The convention can be defined in the configuration with `unit-convention`
+/// and `unit-space`.
+///
+/// # Examples
+///
+/// Here is a list of mistakes that trigger this rule:
+/// - `10kg`, should be `10 kg`
+/// - `3GB`, should be `3 GB`
+/// - `5 %`, should be `5%` in English, while `5%` should be `5 %` with the SI convention
+#[derive(Error, Debug, Diagnostic)]
+#[error("The spacing between a number and its unit is not correct")]
+#[diagnostic(code("typope::unit-spacing"), url(docsrs))]
+pub struct TypoUnitSpacing {
+    #[source_code]
+    src: Option<SharedSource>,
+
+    #[label("Invalid spacing here")]
+    span: SourceSpan,
+
+    #[help]
+    help: String,
+
+    replacement: String,
+}
+
+impl TypoUnitSpacing {
+    fn new(span: impl Into<SourceSpan>, unit: &str, replacement: impl Into<String>) -> Self {
+        let replacement = replacement.into();
+        let help = match replacement.chars().next() {
+            None => format!("remove the space before `{unit}`"),
+            Some(NO_BREAK_SPACE) => format!("use a non-breaking space before `{unit}`"),
+            Some(_) => format!("add a space before `{unit}`"),
+        };
+
+        Self {
+            src: None,
+            span: span.into(),
+            help,
+            replacement,
+        }
+    }
+}
+
+impl Typo for TypoUnitSpacing {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    fn with_source(&mut self, src: SharedSource, lintable: &Lintable) {
+        self.src = Some(src);
+        self.span = lintable.source_span(self.span);
+    }
+
+    fn fix(&self) -> Fix {
+        if self.replacement.is_empty() {
+            Fix::Remove { span: self.span }
+        } else {
+            Fix::Replace {
+                span: self.span,
+                replacement: self.replacement.clone(),
+            }
+        }
+    }
+}
+
+/// A rule that detects a missing, extra, or wrong space between a number and its unit.
+///
+/// Currently, it can only find and generate the following typo: [`TypoUnitSpacing`].
+///
+/// It recognizes a bundled list of units (e.g., `kg`, `ms`, `GB`, or `°C`) and the percent sign.
+/// By default, it follows the convention of the language of the text: English and most languages
+/// expect a space before a unit but not before `%` (e.g., `10 kg` and `5%`), while languages such as
+/// German or French follow the SI convention and also expect a space before `%` (e.g., `5 %`).
+/// The space can be a regular one or a non-breaking one:
+///
+/// ```toml
+/// [default]
+/// unit-convention = "si"
+/// unit-space = "non-breaking"
+/// ```
+pub struct Unit {
+    convention: UnitConvention,
+    space: UnitSpace,
+}
+
+impl Unit {
+    /// Creates the rule with the convention to follow and the kind of space to use.
+    ///
+    /// Without a convention, it is deduced from the language of the text.
+    pub fn new(
+        convention: Option<UnitConvention>,
+        space: Option<UnitSpace>,
+        language: Option<&str>,
+    ) -> Self {
+        let convention = convention.unwrap_or_else(|| {
+            if language.is_some_and(|language| SI_LANGUAGES.contains(&language)) {
+                UnitConvention::Si
+            } else {
+                UnitConvention::English
+            }
+        });
+
+        Self {
+            convention,
+            space: space.unwrap_or(UnitSpace::Regular),
+        }
+    }
+
+    fn space(&self) -> char {
+        match self.space {
+            UnitSpace::Regular => ' ',
+            UnitSpace::NonBreaking => NO_BREAK_SPACE,
+        }
+    }
+
+    /// Returns the length of the number that starts at this index (e.g., `10` or `1.5`)
+    fn number_at(text: &str, index: usize) -> Option<usize> {
+        let starts_number =
+            char_before(text, index).is_none_or(|c| !c.is_alphanumeric() && !"_.,".contains(c));
+        if !starts_number {
+            return None;
+        }
+
+        let rest = text.get(index..)?;
+        let mut end = 0;
+        for (i, c) in rest.char_indices() {
+            let is_decimal_separator = matches!(c, '.' | ',')
+                && i > 0
+                && char_after(rest, i + 1).is_some_and(|c| c.is_ascii_digit());
+            if !c.is_ascii_digit() && !is_decimal_separator {
+                break;
+            }
+            end = i + 1;
+        }
+
+        (end > 0).then_some(end)
+    }
+
+    /// Returns the unit that starts at this index, if any
+    fn unit_at(text: &str, index: usize) -> Option<&str> {
+        let rest = text.get(index..)?;
+        if rest.starts_with('%') {
+            return rest.get(..1);
+        }
+
+        let len = rest
+            .char_indices()
+            .find(|(_, c)| !c.is_alphabetic() && !"°µ".contains(*c))
+            .map_or(rest.len(), |(i, _)| i);
+        let unit = rest.get(..len)?;
+
+        UNITS.contains(&unit).then_some(unit)
+    }
+
+    fn check_number(&self, text: &str, index: usize) -> Option<Box<dyn Typo>> {
+        let end = index + Self::number_at(text, index)?;
+        let space = char_after(text, end)
+            .filter(|c| [' ', NO_BREAK_SPACE, NARROW_NO_BREAK_SPACE].contains(c));
+        let unit_start = end + space.map_or(0, char::len_utf8);
+        let unit = Self::unit_at(text, unit_start)?;
+
+        let space_expected = unit != "%" || self.convention == UnitConvention::Si;
+        let typo = match space {
+            None if space_expected => TypoUnitSpacing::new((end, 0), unit, self.space()),
+            Some(_) if !space_expected => TypoUnitSpacing::new((end, unit_start - end), unit, ""),
+            Some(' ') if self.space == UnitSpace::NonBreaking => {
+                TypoUnitSpacing::new((end, 1), unit, NO_BREAK_SPACE)
+            }
+            _ => return None,
+        };
+
+        Some(Box::new(typo))
+    }
+}
+
+impl Rule for Unit {
+    fn check(&self, bytes: &[u8]) -> Vec<Box<dyn Typo>> {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Vec::new();
+        };
+        if !looks_like_prose(text) {
+            return Vec::new();
+        }
+
+        text.char_indices()
+            .filter(|(_, c)| c.is_ascii_digit())
+            .filter_map(|(index, _)| self.check_number(text, index))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::config::{UnitConvention, UnitSpace};
+    use crate::lint::{Fix, Rule};
+
+    use super::Unit;
+
+    fn replacement(fix: Fix) -> Option<String> {
+        let Fix::Replace { replacement, .. } = fix else {
+            return None;
+        };
+
+        Some(replacement)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Unit::new(None, None, None).check(br"").is_empty());
+    }
+
+    #[test]
+    fn correct() {
+        let rule = Unit::new(None, None, None);
+        assert!(
+            rule.check(br"it weighs 10 kg and uses 5% of 3 GB")
+                .is_empty()
+        );
+        assert!(rule.check(br"it takes 1.5 ms or 2,5 ms").is_empty());
+    }
+
+    #[test]
+    fn missing_space() {
+        let mut typos = Unit::new(None, None, None).check(br"it weighs 10kg and uses 1.5GB");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (27, 0).into());
+        assert_eq!(typo.help().unwrap().to_string(), "add a space before `GB`");
+        assert_eq!(replacement(typo.fix()).unwrap(), " ");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (12, 0).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn percent() {
+        let mut typos = Unit::new(None, None, None).check(br"it uses 5 % of the CPU");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 1).into());
+        assert!(matches!(typo.fix(), Fix::Remove { .. }));
+        assert!(typos.is_empty());
+
+        let rule = Unit::new(Some(UnitConvention::Si), None, None);
+        assert!(rule.check(br"it uses 5 % of the CPU").is_empty());
+        let mut typos = rule.check(br"it uses 5% of the CPU");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 0).into());
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn convention_from_language() {
+        for language in ["de", "fr", "es"] {
+            let rule = Unit::new(None, None, Some(language));
+            assert!(rule.check(br"it uses 5 % of the CPU").is_empty());
+            assert!(
+                rule.check("it uses 5\u{A0}% of the CPU".as_bytes())
+                    .is_empty()
+            );
+            let mut typos = rule.check(br"it uses 5% of the CPU");
+            let typo = typos.pop().unwrap();
+            assert_eq!(typo.span(), (9, 0).into());
+            assert!(typos.is_empty());
+        }
+
+        for language in ["en", "ja"] {
+            let rule = Unit::new(None, None, Some(language));
+            assert!(rule.check(br"it uses 5% of the CPU").is_empty());
+            assert_eq!(rule.check(br"it uses 5 % of the CPU").len(), 1);
+        }
+
+        let rule = Unit::new(Some(UnitConvention::English), None, Some("fr"));
+        assert_eq!(rule.check(br"it uses 5 % of the CPU").len(), 1);
+    }
+
+    #[test]
+    fn non_breaking_space() {
+        let rule = Unit::new(Some(UnitConvention::Si), Some(UnitSpace::NonBreaking), None);
+        assert!(
+            rule.check("it uses 5\u{A0}% of 3\u{A0}GB".as_bytes())
+                .is_empty()
+        );
+
+        let mut typos = rule.check(br"it uses 5% of 3 GB");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (15, 1).into());
+        assert_eq!(
+            typo.help().unwrap().to_string(),
+            "use a non-breaking space before `GB`"
+        );
+        assert_eq!(replacement(typo.fix()).unwrap(), "\u{A0}");
+        let typo = typos.pop().unwrap();
+        assert_eq!(typo.span(), (9, 0).into());
+        assert_eq!(replacement(typo.fix()).unwrap(), "\u{A0}");
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn not_units() {
+        let rule = Unit::new(None, None, None);
+        assert!(
+            rule.check(br"a timeout of 30s with h264 or utf8 encoding")
+                .is_empty()
+        );
+        assert!(
+            rule.check(br"the 1st item or 10kgs of something")
+                .is_empty()
+        );
+        assert!(rule.check(br"see v1.5GB and foo_10MB files").is_empty());
+    }
+}